@@ -6,8 +6,10 @@ import Header from "./Header"
 import Day404 from './Day404'
 
 
-function AoCApp({ days }) {
+function AoCApp({ years }) {
   const today = 1;
+  const [selectedYear, selectYear] = useState(years[years.length - 1].year)
+  const days = years.find((y) => y.year == selectedYear).days
   const [selectedPage, selectPage] = useState(days[today - 1].title)
   const day_titles = Array.from({ length: 25 }, (_, i) => String(i + 1).padStart(2, ' '))
 
@@ -15,7 +17,11 @@ function AoCApp({ days }) {
     <>
       <div id={'dashoard'}>
         <div id={"header"}>
-          <Header />
+          <Header
+            years={years.map((y) => y.year)}
+            selectedYear={selectedYear}
+            selectYear={selectYear}
+          />
         </div>
         <div id={'sidebar'}>
           <Sidebar pageTitles={day_titles} selectedPage={selectedPage} selectPage={selectPage} />
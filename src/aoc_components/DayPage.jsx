@@ -0,0 +1,36 @@
+import { useEffect } from 'react'
+import '../styles/AoC.css'
+import "../assets/prism.css"
+import Prism from "../assets/prism.js"
+import DaySolver from './DaySolver.jsx';
+
+const SOURCE_ROOT = "https://github.com/AllaVinner/aoc-2024/blob/main/"
+
+
+function DayPage({ puzzle }) {
+  useEffect(() => {
+    Prism.highlightAll();
+  }, []);
+
+  const day = String(puzzle.day).padStart(2, '0')
+
+  return (
+    <>
+      <div id={'day' + puzzle.day}>
+        <h1>
+          Day {day}: {puzzle.title}
+        </h1>
+        <div>----------------------------------------------------</div>
+        <DaySolver
+          year={puzzle.year}
+          day={puzzle.day}
+          aoc_puzzle_link={puzzle.puzzle_url}
+          aoc_code_link={SOURCE_ROOT + puzzle.source_path}
+        />
+        <h2>Part 1: Start Describing the solution</h2>
+      </div >
+    </>
+  )
+}
+
+export default DayPage
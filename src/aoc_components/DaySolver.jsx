@@ -1,4 +1,4 @@
-import { useEffect, useState } from 'react'
+import { useEffect, useRef, useState } from 'react'
 import '../styles/AoC.css'
 import "../assets/prism.css"
 import Prism from "../assets/prism.js"
@@ -6,55 +6,114 @@ import * as wasm from "../../wasm-src/pkg/wasm_src.js";
 import InputBox from './InputBox';
 
 
-function DaySolver({ day, aoc_puzzle_link, aoc_code_link }) {
+function DaySolver({ year, day, aoc_puzzle_link, aoc_code_link }) {
   const [inputContent, setInputContent] = useState("");
   const [part1Ans, setPart1Ans] = useState(null);
   const [part2Ans, setPart2Ans] = useState(null);
   const [part1Err, setPart1Err] = useState(null);
   const [part2Err, setPart2Err] = useState(null);
+  const [explanation, setExplanation] = useState(null);
+  // What the parsed input offers, kept in state so the page re-renders
+  // when it is parsed or freed.
+  const [explainParts, setExplainParts] = useState([]);
+  const [stats, setStats] = useState(null);
+  const [canVisualize, setCanVisualize] = useState(false);
+  const [solving, setSolving] = useState(false);
+  // Latest progress report of the part being solved, if it sent one.
+  const [progress, setProgress] = useState(null);
+  // Worker holding the parsed input, see solveWorker.js.
+  const worker = useRef(null);
+  const examples = wasm.examples(year, day);
 
   useEffect(() => {
     Prism.highlightAll();
   }, []);
 
+  const showResults = (results) => {
+    const part1 = results.find((r) => r.part === 1)
+    const part2 = results.find((r) => r.part === 2)
+    setPart1Ans(part1 ? part1.answer : null)
+    setPart1Err(part1 ? part1.error : null)
+    setPart2Ans(part2 ? part2.answer : null)
+    setPart2Err(part2 ? part2.error : null)
+  }
+
+  const stopWorker = () => {
+    if (worker.current !== null) {
+      worker.current.terminate()
+      worker.current = null
+    }
+    setSolving(false)
+    setProgress(null)
+    setExplainParts([])
+    setStats(null)
+    setCanVisualize(false)
+  }
+
   useEffect(() => {
-    if (inputContent !== "") {
-      try {
-        // let result = wasm.day14_part1(inputContent);
-        let result = wasm.solve(inputContent, day, 1)
-        console.log("Result", result);
-        setPart1Ans(result)
-        setPart1Err(null)
-      } catch (error) {
-        console.log("Error: ", error);
-        setPart1Ans(null)
-        setPart1Err(error)
-      }
-      try {
-        // let result = wasm.day14_part1(inputContent);
-        let result = wasm.solve(inputContent, day, 2)
-        console.log("Result", result);
-        setPart2Ans(result)
-        setPart2Err(null)
-      } catch (error) {
-        console.log("Error: ", error);
-        setPart2Ans(null)
-        setPart2Err(error)
+    setExplanation(null)
+    if (inputContent === "") {
+      showResults([])
+      return
+    }
+    const solver = new Worker(new URL('./solveWorker.js', import.meta.url), { type: 'module' })
+    solver.onmessage = ({ data }) => {
+      switch (data.type) {
+        case "parsed":
+          setExplainParts(data.explainParts)
+          setStats(data.stats)
+          setCanVisualize(data.canVisualize)
+          break
+        case "progress":
+          setProgress({ part: data.part, fraction: data.fraction, message: data.message })
+          break
+        case "results":
+          console.log("Result", data.results);
+          showResults(data.results)
+          setSolving(false)
+          setProgress(null)
+          break
+        case "explanation":
+          setExplanation(data.text)
+          break
       }
-    } else {
-      setPart1Ans(null)
-      setPart1Err(null)
-      setPart2Ans(null)
-      setPart2Err(null)
     }
-
+    worker.current = solver
+    showResults([])
+    setSolving(true)
+    solver.postMessage({ type: "solve", input: inputContent, year, day })
+    // Terminating the worker frees the parsed input once the input changes
+    // or the day is left.
+    return stopWorker
   }, [inputContent])
 
+  const cancel = () => {
+    stopWorker()
+    showResults([1, 2].map((part) => ({ part, answer: null, error: "cancelled" })))
+  }
+
+  const explain = (part) => {
+    if (worker.current !== null) {
+      worker.current.postMessage({ type: "explain", part })
+    }
+  }
+
+  const visualize = () => {
+    if (worker.current !== null) {
+      worker.current.postMessage({ type: "visualize" })
+    }
+  }
+
   const format_response = (ans, err) => {
-    if (ans === null && err === null) {
+    if (solving) {
+      return <> &lt;Solving&gt;</>
+    } else if (ans === null && err === null) {
       return <> &lt;Waiting for Input&gt;</>
     } else if (ans !== null) {
-      return <y>{ans}</y>
+      if (ans.kind === "grid" || ans.kind === "text") {
+        return <pre><y>{ans.display}</y></pre>
+      }
+      return <y>{ans.display}</y>
     } else {
       return <> &lt;Input Error&gt; <br /> <r> {err}</r></>
     }
@@ -66,13 +125,44 @@ function DaySolver({ day, aoc_puzzle_link, aoc_code_link }) {
         inputContent={inputContent}
         setInputContent={setInputContent}
       />
+      {examples.map((example) => (
+        <button key={example.name} onClick={() => setInputContent(example.input)}>
+          Load {example.name}
+        </button>
+      ))}
       <p >
         Part 1: {format_response(part1Ans, part1Err)} <br />
         Part 2: {format_response(part2Ans, part2Err)}<br />
+        {solving && progress !== null && (
+          <>
+            Part {progress.part}: <progress value={progress.fraction} />
+            {progress.message && <> {progress.message}</>}<br />
+          </>
+        )}
+        {solving && <><button onClick={cancel}>Cancel</button><br /></>}
         <a href={aoc_puzzle_link}>Puzzle</a>
         {' '}
         <a href={aoc_code_link}>solution</a>
-      </p > <br />
+        {explainParts.map((part) => (
+          <span key={part}>
+            {' '}
+            <button onClick={() => explain(part)}>Explain part {part}</button>
+          </span>
+        ))}
+        {canVisualize && (
+          <span>
+            {' '}
+            <button onClick={visualize}>Show parsed input</button>
+          </span>
+        )}
+      </p >
+      {stats !== null && (
+        <p>
+          {Object.entries(stats).map(([name, value]) => `${name}: ${value}`).join(", ")}
+        </p>
+      )}
+      {explanation !== null && <pre>{explanation}</pre>}
+      <br />
     </div>
   )
 }
@@ -1,7 +1,12 @@
-import { useState } from 'react'
 import "../styles/AoC.css"
 
-function Header() {
+// Years whose solutions live on a separate site.
+const EXTERNAL_YEARS = [
+  { year: 2023, href: "https://allavinner.github.io/aoc-2023/" },
+]
+
+function Header({ years, selectedYear, selectYear }) {
+  const external = EXTERNAL_YEARS.filter((e) => !years.includes(e.year))
 
   return (
     <>
@@ -12,9 +17,23 @@ function Header() {
         --- Learn Rust through Advent of Code ---
       </div>
       <div>
-        <a className={"a-selected"} hhref="https://allavinner.github.io/aoc-2024/">2024</a>
-        {" "}
-        <a href="https://allavinner.github.io/aoc-2023/">2023</a>
+        {[...years].reverse().map((year) => (
+          <span key={year}>
+            <a
+              className={year == selectedYear ? "a-selected" : ""}
+              onClick={() => selectYear(year)}
+            >
+              {year}
+            </a>
+            {" "}
+          </span>
+        ))}
+        {external.map((e) => (
+          <span key={e.year}>
+            <a href={e.href}>{e.year}</a>
+            {" "}
+          </span>
+        ))}
       </div>
     </>
   )
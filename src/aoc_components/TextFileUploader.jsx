@@ -11,7 +11,7 @@ function TextFileUpload({ setText }) {
       () => {
         // this will then display a text file
         console.log('in listner before asignment')
-        setText(fileReader.result.replace(/\r/g, ''));
+        setText(fileReader.result);
         console.log('in listner after asignment')
       },
       false,
@@ -0,0 +1,62 @@
+import * as wasm from "../../wasm-src/pkg/wasm_src.js";
+
+// Solves one day's input off the main thread, so that slow parts leave the
+// page responsive. The page starts a worker per input and terminates it to
+// cancel a solve, which also frees the parsed input.
+
+// Solver steps each part may spend before giving up, so that a slow solve
+// reports an error instead of running on.
+const STEP_BUDGET = 200_000_000;
+
+let parsedInput = null
+
+const tryCall = (f) => {
+  try {
+    return f()
+  } catch (error) {
+    return error
+  }
+}
+
+self.onmessage = ({ data }) => {
+  switch (data.type) {
+    case "solve": {
+      let results = []
+      try {
+        parsedInput = new wasm.ParsedInput(data.input, data.year, data.day)
+        self.postMessage({
+          type: "parsed",
+          explainParts: Array.from(parsedInput.explain_parts()),
+          stats: parsedInput.stats(),
+          canVisualize: parsedInput.visualize() !== undefined,
+        })
+        results = Array.from(parsedInput.parts()).map((part) => {
+          const onProgress = (fraction, message) =>
+            self.postMessage({ type: "progress", part, fraction, message })
+          try {
+            const answer = parsedInput.solve_with_progress(part, onProgress, STEP_BUDGET)
+            return { part, answer, error: null }
+          } catch (error) {
+            return { part, answer: null, error }
+          }
+        })
+      } catch (error) {
+        results = [1, 2].map((part) => ({ part, answer: null, error }))
+      }
+      self.postMessage({ type: "results", results })
+      break
+    }
+    case "explain":
+      self.postMessage({
+        type: "explanation",
+        text: tryCall(() => parsedInput.explain(data.part)),
+      })
+      break
+    case "visualize":
+      self.postMessage({
+        type: "explanation",
+        text: tryCall(() => parsedInput.visualize()),
+      })
+      break
+  }
+}
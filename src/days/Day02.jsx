@@ -1,33 +0,0 @@
-import { useEffect } from 'react'
-import '../styles/AoC.css'
-import "../assets/prism.css"
-import Prism from "../assets/prism.js"
-import DaySolver from '../aoc_components/DaySolver.jsx';
-
-
-function Day02() {
-  const puzzle_link = "https://adventofcode.com/2024/day/2"
-  const code_link = "https://github.com/AllaVinner/aoc-2024/blob/main/wasm-src/src/days/day_02.rs"
-  useEffect(() => {
-    Prism.highlightAll();
-  }, []);
-
-  return (
-    <>
-      <div id={'day2'}>
-        <h1>
-          Day 02: Red-Nosed Report
-        </h1>
-        <div>----------------------------------------------------</div>
-        <DaySolver
-          day={2}
-          aoc_puzzle_link={puzzle_link}
-          aoc_code_link={code_link}
-        />
-        <h2>Part 1: Start Describing the solution</h2>
-      </div >
-    </>
-  )
-}
-
-export default Day02
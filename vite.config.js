@@ -9,5 +9,10 @@ export default defineConfig({
   plugins: [react(),
   wasm(),
   topLevelAwait()],
+  // The solver runs in a worker, which loads the wasm module too.
+  worker: {
+    format: 'es',
+    plugins: () => [wasm(), topLevelAwait()],
+  },
   base: 'aoc-2024' // for github actions
 })
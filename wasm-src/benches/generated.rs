@@ -0,0 +1,61 @@
+//! Times every part of every registered day on generated inputs of growing
+//! size, to see how each solution scales.
+//!
+//! ```text
+//! cargo bench --bench generated
+//! ```
+
+use std::time::{Duration, Instant};
+use wasm_src::aoc_solver;
+use wasm_src::budget::Budget;
+use wasm_src::rng::Rng;
+
+const SIZES: &[usize] = &[10, 30, 100];
+/// Parts that run out of steps are reported instead of timed.
+const MAX_STEPS: u64 = 50_000_000;
+const RUNS: u32 = 5;
+const SEED: u64 = 0x2024;
+
+/// The fastest of a few runs, `None` if the part failed.
+fn time(run: impl Fn() -> bool) -> Option<Duration> {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            match run() {
+                true => Some(start.elapsed()),
+                false => None,
+            }
+        })
+        .collect::<Option<Vec<Duration>>>()?
+        .into_iter()
+        .min()
+}
+
+fn main() {
+    print!("{:>4} {:>3} {:>4}", "year", "day", "part");
+    for size in SIZES {
+        print!(" {:>12}", format!("size {size}"));
+    }
+    println!();
+    for info in aoc_solver::puzzles() {
+        let solver = aoc_solver::solver(info.year, info.day).unwrap();
+        let inputs: Vec<String> = SIZES
+            .iter()
+            .map(|size| solver.generate(&mut Rng::new(SEED), *size))
+            .collect();
+        for part in info.parts.iter().copied() {
+            print!("{:>4} {:>3} {:>4}", info.year, info.day, part);
+            for input in &inputs {
+                let solve = || {
+                    let budget = Budget::max_steps(MAX_STEPS);
+                    solver.solve_with_budget(input, part, &budget).is_ok()
+                };
+                match time(solve) {
+                    Some(elapsed) => print!(" {:>12}", format!("{elapsed:.2?}")),
+                    None => print!(" {:>12}", "failed"),
+                }
+            }
+            println!();
+        }
+    }
+}
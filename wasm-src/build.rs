@@ -0,0 +1,86 @@
+//! Generates the day registry.
+//!
+//! Every `src/days/yYYYY/day_NN.rs` file is picked up as a module and its
+//! `Solution` is registered under year `YYYY`, day `NN`. Both are compiled
+//! only with the day's `yYYYY_day_NN` feature, which must be declared in
+//! `Cargo.toml`. Each day also gets a test checking its registered examples.
+//! `day_template.rs` is compiled and checked the same way in tests, so it
+//! keeps up with the `Solver` trait.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Entries of `dir` whose file stem is `prefix` followed by a number.
+fn numbered(dir: &Path, prefix: &str) -> Vec<(i32, String)> {
+    let mut entries: Vec<(i32, String)> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let stem = path.file_stem()?.to_str()?.to_string();
+            let number = stem.strip_prefix(prefix)?.parse().ok()?;
+            Some((number, stem))
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn main() {
+    let days_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/days");
+    println!("cargo:rerun-if-changed={}", days_dir.display());
+
+    let mut modules = String::new();
+    let mut registry = String::new();
+    let mut example_tests = String::new();
+    let mut features = Vec::new();
+    for (year, year_module) in numbered(&days_dir, "y") {
+        let year_dir = days_dir.join(&year_module);
+        if !year_dir.is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+        modules.push_str(&format!("pub mod {year_module} {{\n"));
+        for (day, module) in numbered(&year_dir, "day_") {
+            features.push(format!("feature = \"{year_module}_{module}\""));
+            let feature = format!("#[cfg(feature = \"{year_module}_{module}\")]");
+            modules.push_str(&format!(
+                "    {feature}\n    #[path = {:?}]\n    pub mod {};\n",
+                year_dir.join(format!("{module}.rs")),
+                module
+            ));
+            registry.push_str(&format!(
+                "    {feature}\n    crate::aoc_solver::Registration {{ year: {year}, day: {day}, solver: &{year_module}::{module}::Solution, source: \"wasm-src/src/days/{year_module}/{module}.rs\" }},\n"
+            ));
+            example_tests.push_str(&format!(
+                "    {feature}\n    #[test]\n    fn {year_module}_{module}() {{\n        assert_examples(crate::aoc_solver::solver({year}, {day}).unwrap());\n    }}\n"
+            ));
+        }
+        modules.push_str("}\n");
+    }
+
+    // The template parses with nom, so it is only compiled along with it.
+    let template = "nom";
+    modules.push_str(&format!(
+        "#[cfg(all(test, feature = {template:?}))]\n#[path = {:?}]\nmod day_template;\n",
+        days_dir.join("day_template.rs")
+    ));
+    example_tests.push_str(&format!(
+        "    #[cfg(feature = {template:?})]\n    #[test]\n    fn day_template() {{\n        assert_examples(&super::day_template::Solution);\n    }}\n"
+    ));
+    features.push(format!("feature = {template:?}"));
+
+    // Only compiled when some test calls it, or it would be dead code.
+    let assert_examples = format!(
+        "    #[cfg(any({}))]\n    fn assert_examples(solver: &dyn crate::aoc_solver::DynSolver) {{\n        \
+         let failures: Vec<String> = solver\n            .examples()\n            .iter()\n            \
+         .flat_map(|example| example.check(solver))\n            .collect();\n        \
+         assert!(failures.is_empty(), \"{{}}\", failures.join(\"\\n\"));\n    }}\n\n",
+        features.join(", ")
+    );
+    let out = format!(
+        "{modules}\npub static REGISTRY: &[crate::aoc_solver::Registration] = &[\n{registry}];\n\n#[cfg(test)]\nmod examples {{\n{assert_examples}{example_tests}}}\n"
+    );
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, out).unwrap();
+}
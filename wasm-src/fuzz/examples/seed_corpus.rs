@@ -0,0 +1,24 @@
+//! Writes the examples of every registered day to its fuzz target's corpus,
+//! `corpus/<target>/<example>`.
+//!
+//! ```text
+//! cargo run --example seed_corpus
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use wasm_src::aoc_solver;
+
+fn main() -> io::Result<()> {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus");
+    for info in aoc_solver::puzzles() {
+        let dir = corpus.join(wasm_src_fuzz::target(info.year, info.day));
+        fs::create_dir_all(&dir)?;
+        for example in aoc_solver::examples(info.year, info.day) {
+            fs::write(dir.join(example.name.replace(' ', "_")), example.input)?;
+        }
+        println!("{}", dir.display());
+    }
+    Ok(())
+}
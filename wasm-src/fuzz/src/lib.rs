@@ -0,0 +1,27 @@
+//! Shared code of the fuzz targets, one per registered day, named as
+//! [`target`] says.
+
+use wasm_src::aoc_solver;
+use wasm_src::budget::Budget;
+
+/// Steps each part may take, so inputs that make a part run for long end
+/// with a budget error instead of a fuzzer timeout.
+pub const MAX_STEPS: u64 = 100_000;
+
+/// Name of a day's fuzz target, and of its corpus directory.
+pub fn target(year: i32, day: i32) -> String {
+    format!("y{year}_day_{day:02}")
+}
+
+/// Parses `data` as a day's input and solves every part of it. Errors are
+/// expected, only panics are findings.
+pub fn solve(year: i32, day: i32, data: &[u8]) {
+    let solver = aoc_solver::solver(year, day).unwrap();
+    let input = String::from_utf8_lossy(data);
+    if let Ok(parsed) = solver.parse(&input) {
+        for part in solver.parts() {
+            let budget = Budget::max_steps(MAX_STEPS);
+            let _ = solver.solve_parsed(parsed.as_ref(), part, &budget);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::convert::TryFrom;
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+/// A solver's answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    /// A picture, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::BigInteger(_) => "big_integer",
+            Answer::Text(_) => "text",
+            Answer::Grid(_) => "grid",
+        }
+    }
+
+    /// Numeric value of the answer, if it has one.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(v) => Some(*v as i128),
+            Answer::BigInteger(v) => Some(*v),
+            Answer::Text(_) | Answer::Grid(_) => None,
+        }
+    }
+
+    /// Converts to a JS object `{ kind, display, value }`.
+    ///
+    /// Numeric values are passed as `BigInt` so that large answers keep
+    /// their precision.
+    pub fn to_js(&self) -> JsValue {
+        let value: JsValue = match self {
+            Answer::Integer(v) => js_sys::BigInt::from(*v).into(),
+            Answer::BigInteger(v) => js_sys::BigInt::from(*v).into(),
+            Answer::Text(s) => JsValue::from_str(s),
+            Answer::Grid(rows) => rows
+                .iter()
+                .map(|r| JsValue::from_str(r))
+                .collect::<js_sys::Array>()
+                .into(),
+        };
+        let object = js_sys::Object::new();
+        let set = |key: &str, v: &JsValue| js_sys::Reflect::set(&object, &key.into(), v);
+        set("kind", &self.kind().into()).unwrap();
+        set("display", &self.to_string().into()).unwrap();
+        set("value", &value).unwrap();
+        object.into()
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(v) => write!(f, "{v}"),
+            Answer::BigInteger(v) => write!(f, "{v}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(v: i32) -> Answer {
+        Answer::Integer(v as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(v: i64) -> Answer {
+        Answer::Integer(v)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(v: u64) -> Answer {
+        match i64::try_from(v) {
+            Ok(v) => Answer::Integer(v),
+            Err(_) => Answer::BigInteger(v as i128),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(v: i128) -> Answer {
+        match i64::try_from(v) {
+            Ok(v) => Answer::Integer(v),
+            Err(_) => Answer::BigInteger(v),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(v: usize) -> Answer {
+        Answer::from(v as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_from_u64() {
+        assert_eq!(Answer::from(7u64), Answer::Integer(7));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX).as_i128(), Some(u64::MAX as i128));
+        assert_eq!(Answer::from(-7i128), Answer::Integer(-7));
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Answer::Grid(vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(Answer::Integer(-3).to_string(), "-3");
+    }
+}
@@ -1,16 +1,639 @@
+use crate::answer::Answer;
+use crate::budget::Budget;
 use crate::days;
+use crate::error::{AoCError, ErrorKind};
+use crate::normalize::Normalize;
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use std::any::Any;
 
-pub fn solve(input: &str, day: i32, part: i32) -> Result<String, String> {
-    match day {
-        1 => days::day_01::solve(input, part),
-        2 => days::day_02::solve(input, part),
-        3 => days::day_03::solve(input, part),
-        4 => days::day_04::solve(input, part),
-        5 => days::day_05::solve(input, part),
-        6 => days::day_06::solve(input, part),
-        7 => days::day_07::solve(input, part),
-        8 => days::day_08::solve(input, part),
-        9 => days::day_09::solve(input, part),
-        i => Err(format!("day {i} is not implemented.")),
+/// A day's solution.
+///
+/// The input is normalized as `NORMALIZE` says, then parsed once into
+/// `Parsed`, which every part then reads.
+/// Days with more than the two standard parts list them in `EXTRA_PARTS`
+/// and answer them in `extra`. Parts that can walk through how their answer
+/// was found list them in `EXPLAIN_PARTS` and implement `explain`.
+/// `stats` describes the parsed input as named counts, e.g. the size of a
+/// grid and what is on it, and `visualize` draws it, so the page can show
+/// what was parsed.
+///
+/// Parts that also have a slow but obviously correct implementation list
+/// them in `REFERENCE_PARTS` and answer them in `reference`, which the
+/// differential tests compare against the real parts.
+///
+/// `generate` writes a random input in the puzzle's format, with `size`
+/// setting its scale, e.g. the number of lines or the side of a grid. It is
+/// used by the fuzz, differential and benchmark suites. `STRUCTURE` says
+/// which pieces of an input can be removed when shrinking a failing one.
+///
+/// Examples from the puzzle text go in `EXAMPLES`, together with their
+/// expected answers; every one of them is checked by a generated test.
+///
+/// Parts that may run for long call `budget.step()?` in their hot loops so
+/// the solve can be cancelled or stopped once its step budget is spent.
+pub trait Solver {
+    type Parsed: 'static;
+    const TITLE: &'static str;
+    const EXTRA_PARTS: &'static [i32] = &[];
+    const EXPLAIN_PARTS: &'static [i32] = &[];
+    const REFERENCE_PARTS: &'static [i32] = &[];
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+    const EXAMPLES: &'static [Example] = &[];
+    const STRUCTURE: Structure = Structure::Lines(&[]);
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError>;
+    fn part1(parsed: &Self::Parsed, budget: &Budget) -> Result<Answer, AoCError>;
+    fn part2(parsed: &Self::Parsed, budget: &Budget) -> Result<Answer, AoCError>;
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    fn extra(_parsed: &Self::Parsed, part: i32, _budget: &Budget) -> Result<Answer, AoCError> {
+        Err(AoCError::new(ErrorKind::Other(format!(
+            "part {part} is not implemented"
+        ))))
+    }
+
+    fn explain(_parsed: &Self::Parsed, part: i32) -> Result<String, AoCError> {
+        Err(AoCError::new(ErrorKind::Other(format!(
+            "part {part} has no explanation"
+        ))))
+    }
+
+    fn reference(_parsed: &Self::Parsed, part: i32, _budget: &Budget) -> Result<Answer, AoCError> {
+        Err(AoCError::new(ErrorKind::Other(format!(
+            "part {part} has no reference solution"
+        ))))
+    }
+
+    fn stats(_parsed: &Self::Parsed) -> Vec<(&'static str, usize)> {
+        Vec::new()
+    }
+
+    fn visualize(_parsed: &Self::Parsed) -> Option<String> {
+        None
+    }
+
+    /// Whether the normalized input has this day's format, used by
+    /// [`identify`]. Days whose parser accepts nearly anything narrow it
+    /// down with a shape check.
+    fn looks_like(input: &str) -> bool {
+        Self::parse(input).is_ok()
+    }
+}
+
+/// Object-safe view of a [`Solver`], used by the registry.
+///
+/// Parsed input is passed around as `dyn Any` and must come from the same
+/// solver's `parse`.
+pub trait DynSolver: Sync {
+    fn title(&self) -> &'static str;
+    fn parts(&self) -> Vec<i32>;
+    fn explain_parts(&self) -> Vec<i32>;
+    fn reference_parts(&self) -> Vec<i32>;
+    fn examples(&self) -> &'static [Example];
+    fn structure(&self) -> Structure;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AoCError>;
+    fn looks_like(&self, input: &str) -> bool;
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+    fn solve_parsed(
+        &self,
+        parsed: &dyn Any,
+        part: i32,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError>;
+    fn explain_parsed(&self, parsed: &dyn Any, part: i32) -> Result<String, AoCError>;
+    fn reference_parsed(
+        &self,
+        parsed: &dyn Any,
+        part: i32,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError>;
+    fn stats_parsed(&self, parsed: &dyn Any) -> Vec<(&'static str, usize)>;
+    fn visualize_parsed(&self, parsed: &dyn Any) -> Option<String>;
+
+    fn solve(&self, input: &str, part: i32) -> Result<Answer, AoCError> {
+        self.solve_with_budget(input, part, &Budget::unlimited())
+    }
+
+    fn solve_with_budget(
+        &self,
+        input: &str,
+        part: i32,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError> {
+        let parsed = self.parse(input)?;
+        self.solve_parsed(parsed.as_ref(), part, budget)
+    }
+
+    fn explain(&self, input: &str, part: i32) -> Result<String, AoCError> {
+        let parsed = self.parse(input)?;
+        self.explain_parsed(parsed.as_ref(), part)
+    }
+}
+
+fn downcast<S: Solver>(parsed: &dyn Any) -> &S::Parsed {
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed input belongs to another solver")
+}
+
+impl<S: Solver + Sync> DynSolver for S {
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parts(&self) -> Vec<i32> {
+        [1, 2].iter().chain(S::EXTRA_PARTS).copied().collect()
+    }
+
+    fn explain_parts(&self) -> Vec<i32> {
+        S::EXPLAIN_PARTS.to_vec()
+    }
+
+    fn reference_parts(&self) -> Vec<i32> {
+        S::REFERENCE_PARTS.to_vec()
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn structure(&self) -> Structure {
+        S::STRUCTURE
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AoCError> {
+        Ok(Box::new(S::parse(&S::NORMALIZE.apply(input))?))
+    }
+
+    fn looks_like(&self, input: &str) -> bool {
+        S::looks_like(&S::NORMALIZE.apply(input))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        S::generate(rng, size)
+    }
+
+    fn solve_parsed(
+        &self,
+        parsed: &dyn Any,
+        part: i32,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError> {
+        let parsed = downcast::<S>(parsed);
+        match part {
+            1 => S::part1(parsed, budget),
+            2 => S::part2(parsed, budget),
+            i => S::extra(parsed, i, budget),
+        }
+    }
+
+    fn explain_parsed(&self, parsed: &dyn Any, part: i32) -> Result<String, AoCError> {
+        S::explain(downcast::<S>(parsed), part)
+    }
+
+    fn reference_parsed(
+        &self,
+        parsed: &dyn Any,
+        part: i32,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError> {
+        S::reference(downcast::<S>(parsed), part, budget)
+    }
+
+    fn stats_parsed(&self, parsed: &dyn Any) -> Vec<(&'static str, usize)> {
+        S::stats(downcast::<S>(parsed))
+    }
+
+    fn visualize_parsed(&self, parsed: &dyn Any) -> Option<String> {
+        S::visualize(downcast::<S>(parsed))
+    }
+}
+
+/// An example input with the answers it should give.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    /// Expected answer, as displayed, for each part the example covers.
+    pub answers: &'static [(i32, &'static str)],
+}
+
+impl Example {
+    /// Solves every part the example covers and describes each answer or
+    /// error that differs from the expected one.
+    pub fn check(&self, solver: &dyn DynSolver) -> Vec<String> {
+        self.answers
+            .iter()
+            .filter_map(|&(part, expected)| {
+                let got = match solver.solve(self.input, part) {
+                    Ok(answer) => answer.to_string(),
+                    Err(e) => e.to_string(),
+                };
+                match got == expected {
+                    true => None,
+                    false => Some(format!(
+                        "example {:?} part {part}: expected {expected}, got {got}",
+                        self.name
+                    )),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A day's input, parsed once and kept for solving and explaining parts.
+pub struct Puzzle {
+    year: i32,
+    day: i32,
+    solver: &'static dyn DynSolver,
+    parsed: Box<dyn Any>,
+    input_bytes: usize,
+    input_lines: usize,
+}
+
+impl Puzzle {
+    pub fn new(input: &str, year: i32, day: i32) -> Result<Puzzle, String> {
+        let solver = solver(year, day).ok_or_else(|| not_implemented(year, day))?;
+        let parsed = solver
+            .parse(input)
+            .map_err(|e| with_identify_hint(e.to_string(), input, year, day))?;
+        Ok(Puzzle {
+            year,
+            day,
+            solver,
+            parsed,
+            input_bytes: input.len(),
+            input_lines: input.lines().count(),
+        })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn day(&self) -> i32 {
+        self.day
+    }
+
+    pub fn parts(&self) -> Vec<i32> {
+        self.solver.parts()
+    }
+
+    pub fn explain_parts(&self) -> Vec<i32> {
+        self.solver.explain_parts()
+    }
+
+    pub fn solve(&self, part: i32) -> Result<Answer, String> {
+        self.solve_with_budget(part, &Budget::unlimited())
+    }
+
+    pub fn solve_with_budget(&self, part: i32, budget: &Budget) -> Result<Answer, String> {
+        if !self.parts().contains(&part) {
+            return Err(format!("day {} part {part} is not implemented", self.day));
+        }
+        self.solver
+            .solve_parsed(self.parsed.as_ref(), part, budget)
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn solve_all(&self) -> DayResult {
+        self.solve_all_with_budget(&Budget::unlimited())
+    }
+
+    /// Solves every part, with `budget` shared between them.
+    pub fn solve_all_with_budget(&self, budget: &Budget) -> DayResult {
+        self.parts()
+            .into_iter()
+            .map(|part| (part, self.solve_with_budget(part, budget)))
+            .collect()
+    }
+
+    pub fn explain(&self, part: i32) -> Result<String, String> {
+        if !self.explain_parts().contains(&part) {
+            return Err(format!("day {} part {part} has no explanation", self.day));
+        }
+        self.solver
+            .explain_parsed(self.parsed.as_ref(), part)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Size of the input the puzzle was parsed from, in bytes and lines.
+    pub fn input_size(&self) -> (usize, usize) {
+        (self.input_bytes, self.input_lines)
+    }
+
+    /// Named counts describing the parsed input, see [`Solver::stats`].
+    pub fn stats(&self) -> Vec<(&'static str, usize)> {
+        self.solver.stats_parsed(self.parsed.as_ref())
+    }
+
+    /// A drawing of the parsed input, if the day has one.
+    pub fn visualize(&self) -> Option<String> {
+        self.solver.visualize_parsed(self.parsed.as_ref())
+    }
+}
+
+/// Year used when the caller does not name one.
+pub const DEFAULT_YEAR: i32 = 2024;
+
+pub struct Registration {
+    pub year: i32,
+    pub day: i32,
+    pub solver: &'static dyn DynSolver,
+    /// Path of the day's module, relative to the repository root.
+    pub source: &'static str,
+}
+
+/// Everything the front end needs to know to show a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleInfo {
+    pub year: i32,
+    pub day: i32,
+    pub title: &'static str,
+    pub puzzle_url: String,
+    pub source_path: &'static str,
+    pub parts: Vec<i32>,
+    pub explain_parts: Vec<i32>,
+    /// Optional features beyond solving, e.g. `"explain"`.
+    pub extras: Vec<&'static str>,
+}
+
+impl Registration {
+    pub fn info(&self) -> PuzzleInfo {
+        let explain_parts = self.solver.explain_parts();
+        let mut extras = Vec::new();
+        if !explain_parts.is_empty() {
+            extras.push("explain");
+        }
+        if !self.solver.examples().is_empty() {
+            extras.push("examples");
+        }
+        PuzzleInfo {
+            year: self.year,
+            day: self.day,
+            title: self.solver.title(),
+            puzzle_url: format!("https://adventofcode.com/{}/day/{}", self.year, self.day),
+            source_path: self.source,
+            parts: self.solver.parts(),
+            explain_parts,
+            extras,
+        }
+    }
+}
+
+fn not_implemented(year: i32, day: i32) -> String {
+    format!("day {day} of {year} is not implemented.")
+}
+
+/// Registered days, as `(year, day)`, whose input format `input` matches.
+///
+/// Blank input matches no day.
+pub fn identify(input: &str) -> Vec<(i32, i32)> {
+    if input.trim().is_empty() {
+        return Vec::new();
+    }
+    days::REGISTRY
+        .iter()
+        .filter(|r| r.solver.looks_like(input))
+        .map(|r| (r.year, r.day))
+        .collect()
+}
+
+/// Adds which days `input` looks like to a parse error for `year`/`day`.
+fn with_identify_hint(error: String, input: &str, year: i32, day: i32) -> String {
+    let others: Vec<String> = identify(input)
+        .into_iter()
+        .filter(|other| *other != (year, day))
+        .map(|(other_year, other_day)| match other_year == year {
+            true => format!("day {other_day}"),
+            false => format!("day {other_day} of {other_year}"),
+        })
+        .collect();
+    match others.is_empty() {
+        true => error,
+        false => format!(
+            "{error}\nhelp: this looks like a {} input",
+            others.join(" or ")
+        ),
+    }
+}
+
+/// Metadata of every registered day, ordered by year and day.
+pub fn puzzles() -> Vec<PuzzleInfo> {
+    days::REGISTRY.iter().map(Registration::info).collect()
+}
+
+/// Years with at least one registered day, in ascending order.
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<i32> = days::REGISTRY.iter().map(|r| r.year).collect();
+    years.dedup();
+    years
+}
+
+pub fn solver(year: i32, day: i32) -> Option<&'static dyn DynSolver> {
+    days::REGISTRY
+        .iter()
+        .find(|r| r.year == year && r.day == day)
+        .map(|r| r.solver)
+}
+
+/// The registered examples of a day, empty for unknown days.
+pub fn examples(year: i32, day: i32) -> &'static [Example] {
+    solver(year, day).map_or(&[], |s| s.examples())
+}
+
+/// A random input for a day, the same for the same `seed` and `size`.
+pub fn generate(year: i32, day: i32, seed: u64, size: usize) -> Option<String> {
+    solver(year, day).map(|s| s.generate(&mut Rng::new(seed), size))
+}
+
+/// The year's registered days together with the parts they implement.
+pub fn available(year: i32) -> Vec<(i32, Vec<i32>)> {
+    days::REGISTRY
+        .iter()
+        .filter(|r| r.year == year)
+        .map(|r| (r.day, r.solver.parts()))
+        .collect()
+}
+
+pub fn solve(input: &str, year: i32, day: i32, part: i32) -> Result<Answer, String> {
+    solve_with_budget(input, year, day, part, &Budget::unlimited())
+}
+
+pub fn solve_with_budget(
+    input: &str,
+    year: i32,
+    day: i32,
+    part: i32,
+    budget: &Budget,
+) -> Result<Answer, String> {
+    match solver(year, day) {
+        Some(s) if s.parts().contains(&part) => {
+            Puzzle::new(input, year, day)?.solve_with_budget(part, budget)
+        }
+        Some(_) => Err(format!("day {day} part {part} is not implemented")),
+        None => Err(not_implemented(year, day)),
+    }
+}
+
+/// Answer, or rendered error, for every part of a day.
+pub type DayResult = Vec<(i32, Result<Answer, String>)>;
+
+/// Parses the input once and solves every part of the day.
+///
+/// A parse error fails the whole day, errors in a single part are kept
+/// next to the other parts' answers.
+pub fn solve_day(input: &str, year: i32, day: i32) -> Result<DayResult, String> {
+    solve_day_with_budget(input, year, day, &Budget::unlimited())
+}
+
+pub fn solve_day_with_budget(
+    input: &str,
+    year: i32,
+    day: i32,
+    budget: &Budget,
+) -> Result<DayResult, String> {
+    Ok(Puzzle::new(input, year, day)?.solve_all_with_budget(budget))
+}
+
+pub fn explain(input: &str, year: i32, day: i32, part: i32) -> Result<String, String> {
+    match solver(year, day) {
+        Some(s) if s.explain_parts().contains(&part) => {
+            Puzzle::new(input, year, day)?.explain(part)
+        }
+        Some(_) => Err(format!("day {day} part {part} has no explanation")),
+        None => Err(not_implemented(year, day)),
+    }
+}
+
+#[cfg(all(test, feature = "y2024_day_01", feature = "y2024_day_08"))]
+mod tests {
+    use super::{
+        available, examples, explain, identify, puzzles, solve, solve_day, solver, years, Example,
+        Puzzle, DEFAULT_YEAR,
+    };
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_available() {
+        let days = available(2024);
+        assert_eq!(days.first(), Some(&(1, vec![1, 2])));
+        assert!(days.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(available(1999).is_empty());
+        assert!(years().contains(&DEFAULT_YEAR));
+    }
+
+    #[test]
+    fn test_puzzles() {
+        let puzzles = puzzles();
+        let day_08 = puzzles
+            .iter()
+            .find(|p| p.year == 2024 && p.day == 8)
+            .unwrap();
+        assert_eq!(day_08.title, "Resonant Collinearity");
+        assert_eq!(day_08.puzzle_url, "https://adventofcode.com/2024/day/8");
+        assert_eq!(day_08.source_path, "wasm-src/src/days/y2024/day_08.rs");
+        assert_eq!(day_08.extras, vec!["explain", "examples"]);
+        assert!(puzzles.iter().all(|p| !p.title.is_empty()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            solve("3   4\n4 x 3\n", 2024, 1, 1),
+            Err("error: unexpected character 'x', expected digit\n --> 2:3\n  |\n2 | 4 x 3\n  |   ^".to_string())
+        );
+    }
+
+    #[test]
+    fn test_solve_day() {
+        let results = solve_day("3   4\n4   3\n", 2024, 1).unwrap();
+        assert_eq!(
+            results,
+            vec![(1, Ok(Answer::Integer(0))), (2, Ok(Answer::Integer(7)))]
+        );
+        assert!(solve_day("3   x\n", 2024, 1).is_err());
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let puzzle = Puzzle::new("....\n..a.\n.a..\n....\n", 2024, 8).unwrap();
+        assert_eq!((puzzle.year(), puzzle.day()), (2024, 8));
+        assert_eq!(puzzle.solve(1), Ok(Answer::Integer(2)));
+        assert_eq!(puzzle.solve(2), Ok(Answer::Integer(4)));
+        assert!(puzzle.explain(2).unwrap().contains("Num matches: 4"));
+        assert!(puzzle.solve(3).is_err());
+        assert_eq!(puzzle.input_size(), (20, 4));
+        let stats = vec![
+            ("rows", 4),
+            ("columns", 4),
+            ("frequencies", 1),
+            ("antennas", 2),
+        ];
+        assert_eq!(puzzle.stats(), stats);
+        assert_eq!(puzzle.visualize().unwrap(), "....\n..a.\n.a..\n....");
+        assert!(Puzzle::new("3   x\n", 2024, 1).is_err());
+        let day_1 = Puzzle::new("3   4\n4   3\n", 2024, 1).unwrap();
+        assert_eq!(day_1.stats(), vec![("pairs", 2)]);
+        assert_eq!(day_1.visualize(), None);
+    }
+
+    #[test]
+    fn test_explain() {
+        assert!(explain("....\n..a.\n.a..\n....\n", 2024, 8, 1)
+            .unwrap()
+            .contains("Num matches: 2"));
+        assert!(explain("3   4\n", 2024, 1, 1).is_err());
+        let day_8 = solver(2024, 8).unwrap();
+        assert!(day_8.explain("....\n", 3).is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        let names: Vec<&str> = examples(2024, 8).iter().map(|e| e.name).collect();
+        assert_eq!(names, vec!["example", "basic"]);
+        assert!(examples(2024, 26).is_empty());
+        let wrong = Example {
+            name: "wrong",
+            input: "3   4\n4   3\n",
+            answers: &[(1, "0"), (2, "8"), (3, "1")],
+        };
+        assert_eq!(
+            wrong.check(solver(2024, 1).unwrap()),
+            vec![
+                "example \"wrong\" part 2: expected 8, got 7",
+                "example \"wrong\" part 3: expected 1, got error: part 3 is not implemented",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalized_input() {
+        let unix = "3   4\n4   3\n1   9";
+        let windows = "\u{feff}3   4  \r\n4   3\r\n1   9\r\n\r\n";
+        assert_eq!(solve_day(windows, 2024, 1), solve_day(unix, 2024, 1));
+    }
+
+    #[cfg(all(feature = "y2024_day_04", feature = "y2024_day_06"))]
+    #[test]
+    fn test_identify() {
+        assert!(identify("3   4\n4   3\n").contains(&(2024, 1)));
+        assert_eq!(identify("XMAS\nSAMX\n"), vec![(2024, 4)]);
+        let day_06 = "....#\n..^..\n.....";
+        assert_eq!(identify(day_06), vec![(2024, 6)]);
+        assert!(identify(" \n").is_empty());
+        let error = solve(day_06, 2024, 1, 1).unwrap_err();
+        assert!(error.ends_with("\nhelp: this looks like a day 6 input"));
+    }
+
+    #[test]
+    fn test_unknown_day_and_part() {
+        assert!(solve("", 2024, 26, 1).is_err());
+        assert!(solve("3   4\n", 2024, 1, 3).is_err());
+        assert_eq!(
+            solve("3   4\n", 2023, 1, 1),
+            Err("day 1 of 2023 is not implemented.".to_string())
+        );
     }
 }
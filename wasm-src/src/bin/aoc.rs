@@ -0,0 +1,550 @@
+//! Command-line runner for the solvers.
+//!
+//! ```text
+//! aoc solve 9 --input data/day_09/main.txt
+//! cat input.txt | aoc solve 1 --part 2
+//! aoc --year 2023 solve 1 --input input.txt
+//! aoc solve 6 --input input.txt --timeout 10
+//! aoc --log day_03=debug solve 3 --input input.txt
+//! aoc check --data ~/aoc-inputs --user alice
+//! aoc record 9 --input ~/aoc-inputs/day_09/main.txt --data ~/aoc-inputs --user alice
+//! aoc generate 5 --seed 7 --size 20 | aoc solve 5
+//! aoc shrink 9 --part 2 --input failing.txt
+//! ```
+
+use clap::{Parser, Subcommand};
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+use wasm_src::aoc_solver::{self, Puzzle};
+use wasm_src::budget::{Budget, CancelToken};
+use wasm_src::golden;
+use wasm_src::log::{self, LogFilter};
+use wasm_src::progress::Progress;
+use wasm_src::shrink::{self, Failure};
+use wasm_src::verify::{AnswerStore, Entry, Key};
+
+/// Hashed answers kept in the repository.
+const ANSWER_STORE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    /// Event year.
+    #[arg(short, long, global = true, default_value_t = aoc_solver::DEFAULT_YEAR)]
+    year: i32,
+    /// Solver logging to stderr, e.g. `debug` or `day_03=trace,info`.
+    #[arg(long, global = true)]
+    log: Option<LogFilter>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, either one part or all of them.
+    Solve {
+        day: i32,
+        /// Part to solve, all parts if omitted.
+        #[arg(short, long)]
+        part: Option<i32>,
+        /// Input file, stdin if omitted or '-'.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        limits: Limits,
+    },
+    /// Walk through how a part's answer is found.
+    Explain {
+        day: i32,
+        #[arg(short, long, default_value_t = 1)]
+        part: i32,
+        /// Input file, stdin if omitted or '-'.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// List the implemented days and parts of the year.
+    List,
+    /// Show which days an input's format matches.
+    Identify {
+        /// Input file, stdin if omitted or '-'.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Check real inputs against their known answers.
+    Check {
+        /// Data directory, `$AOC_DATA` if omitted.
+        #[arg(short, long)]
+        data: Option<PathBuf>,
+        /// Whose hashed answers to check against, `$AOC_USER` if omitted.
+        #[arg(short, long)]
+        user: Option<String>,
+        /// Store of hashed answers.
+        #[arg(long, default_value = ANSWER_STORE)]
+        answers: PathBuf,
+    },
+    /// Record the hashed answers to an input, once they have been accepted
+    /// on adventofcode.com.
+    Record {
+        day: i32,
+        /// Part to record, all parts if omitted.
+        #[arg(short, long)]
+        part: Option<i32>,
+        #[arg(short, long)]
+        input: PathBuf,
+        /// Whose input it is, `$AOC_USER` if omitted.
+        #[arg(short, long)]
+        user: Option<String>,
+        /// Data directory holding the key to hash with, `$AOC_DATA` if
+        /// omitted.
+        #[arg(short, long)]
+        data: Option<PathBuf>,
+        /// Store of hashed answers.
+        #[arg(long, default_value = ANSWER_STORE)]
+        answers: PathBuf,
+    },
+    /// Print a random input for a day.
+    Generate {
+        day: i32,
+        /// The same seed and size give the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Rough number of lines, rows or items.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Shrink an input on which a part panics, or disagrees with its
+    /// reference, and print it as a regression test.
+    Shrink {
+        day: i32,
+        /// Part that panics; a disagreement with the reference if omitted.
+        #[arg(short, long)]
+        part: Option<i32>,
+        /// Input file, stdin if omitted or '-'.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Steps each part may take.
+        #[arg(long, default_value_t = 1_000_000)]
+        max_steps: u64,
+    },
+}
+
+#[derive(clap::Args)]
+struct Limits {
+    /// Give up after this many solver steps, shared by all parts.
+    #[arg(long)]
+    max_steps: Option<u64>,
+    /// Give up after this many seconds, shared by all parts.
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+}
+
+/// Seconds as a `Duration`, rejecting negative, NaN and overflowing ones.
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let seconds: f64 = seconds.parse().map_err(|e| format!("{e}"))?;
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| "expected a finite number of seconds, 0 or more".to_string())
+}
+
+impl Limits {
+    fn budget(&self) -> Budget {
+        let budget = match self.max_steps {
+            Some(steps) => Budget::max_steps(steps),
+            None => Budget::unlimited(),
+        };
+        match self.timeout {
+            Some(timeout) => {
+                let token = CancelToken::new();
+                let timer = token.clone();
+                thread::spawn(move || {
+                    thread::sleep(timeout);
+                    timer.cancel();
+                });
+                budget.with_cancel(token)
+            }
+            None => budget,
+        }
+    }
+}
+
+/// Progress bar on stderr, redrawn when the shown percentage changes.
+#[derive(Default)]
+struct ProgressBar {
+    shown: Cell<Option<u32>>,
+}
+
+impl ProgressBar {
+    const WIDTH: usize = 30;
+
+    fn clear() {
+        eprint!("\r\x1b[K");
+    }
+}
+
+impl Progress for ProgressBar {
+    fn report(&self, fraction: f64, message: Option<&str>) {
+        let percent = (fraction * 100.0) as u32;
+        if self.shown.replace(Some(percent)) == Some(percent) {
+            return;
+        }
+        let filled = (fraction * Self::WIDTH as f64) as usize;
+        ProgressBar::clear();
+        eprint!(
+            "[{}{}] {percent:>3}% {}",
+            "#".repeat(filled),
+            ".".repeat(Self::WIDTH - filled),
+            message.unwrap_or("")
+        );
+    }
+}
+
+fn read_input(path: Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(p) if p.as_os_str() != "-" => fs::read_to_string(p),
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+fn solve(
+    year: i32,
+    day: i32,
+    part: Option<i32>,
+    input: Option<PathBuf>,
+    limits: Limits,
+) -> ExitCode {
+    if aoc_solver::solver(year, day).is_none() {
+        eprintln!("day {day} of {year} is not implemented.");
+        return ExitCode::FAILURE;
+    }
+    let input = match read_input(input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let puzzle = match Puzzle::new(&input, year, day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("Day {day}:\n{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = match part {
+        Some(p) => vec![p],
+        None => puzzle.parts(),
+    };
+    let show_progress = io::stderr().is_terminal();
+    let mut budget = limits.budget();
+    if show_progress {
+        budget = budget.with_progress(ProgressBar::default());
+    }
+    let mut code = ExitCode::SUCCESS;
+    for part in parts {
+        let start = Instant::now();
+        let result = puzzle.solve_with_budget(part, &budget);
+        let elapsed = start.elapsed();
+        if show_progress {
+            ProgressBar::clear();
+        }
+        match result {
+            Ok(answer) if answer.to_string().contains('\n') => {
+                println!("Day {day} part {part} ({elapsed:.2?}):\n{answer}")
+            }
+            Ok(answer) => println!("Day {day} part {part}: {answer} ({elapsed:.2?})"),
+            Err(e) => {
+                eprintln!("Day {day} part {part}:\n{e}");
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+fn explain(year: i32, day: i32, part: i32, input: Option<PathBuf>) -> ExitCode {
+    let input = match read_input(input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match aoc_solver::explain(&input, year, day, part) {
+        Ok(explanation) => {
+            println!("{explanation}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn identify(input: Option<PathBuf>) -> ExitCode {
+    let input = match read_input(input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let matches = aoc_solver::identify(&input);
+    if matches.is_empty() {
+        eprintln!("the input matches no implemented day");
+        return ExitCode::FAILURE;
+    }
+    for (year, day) in matches {
+        let title = aoc_solver::solver(year, day).map_or("", |s| s.title());
+        println!("{year} day {day}: {title}");
+    }
+    ExitCode::SUCCESS
+}
+
+/// The data directory passed or else named in the environment.
+fn data_dir(data: Option<PathBuf>) -> Option<PathBuf> {
+    let dir = data.or_else(|| env::var_os(golden::DATA_DIR_VAR).map(PathBuf::from));
+    if dir.is_none() {
+        eprintln!(
+            "no data directory, pass --data or set {}",
+            golden::DATA_DIR_VAR
+        );
+    }
+    dir
+}
+
+/// The user passed or else named in the environment, if it is a valid
+/// name for the answer store.
+fn user_name(user: Option<String>) -> Result<Option<String>, ()> {
+    match user.or_else(|| env::var(golden::USER_VAR).ok()) {
+        Some(user) if user.is_empty() || user.contains(char::is_whitespace) => {
+            eprintln!("the user name must not be empty or contain spaces");
+            Err(())
+        }
+        user => Ok(user),
+    }
+}
+
+fn check(data: Option<PathBuf>, user: Option<String>, answers: &Path) -> ExitCode {
+    let dir = match data_dir(data) {
+        Some(dir) => dir,
+        None => return ExitCode::FAILURE,
+    };
+    let user = match user_name(user) {
+        Ok(user) => user,
+        Err(()) => return ExitCode::FAILURE,
+    };
+    let store = match AnswerStore::load(answers) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("could not read answers: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match golden::run(&dir, &store, user.as_deref()) {
+        Ok(checks) => {
+            print!("{}", golden::table(&checks));
+            match golden::passed(&checks) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            }
+        }
+        Err(e) => {
+            eprintln!("could not read {}: {e}", dir.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn record(
+    year: i32,
+    day: i32,
+    part: Option<i32>,
+    input: PathBuf,
+    user: Option<String>,
+    data: Option<PathBuf>,
+    answers: &Path,
+) -> ExitCode {
+    let user = match user_name(user) {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            eprintln!("no user, pass --user or set {}", golden::USER_VAR);
+            return ExitCode::FAILURE;
+        }
+        Err(()) => return ExitCode::FAILURE,
+    };
+    let key_path = match data_dir(data) {
+        Some(dir) => dir.join(golden::KEY_FILE),
+        None => return ExitCode::FAILURE,
+    };
+    let key = match Key::load_or_create(&key_path) {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("could not read or create {}: {e}", key_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let (text, mut store) = match (fs::read_to_string(&input), AnswerStore::load(answers)) {
+        (Ok(text), Ok(store)) => (text, store),
+        (Err(e), _) => {
+            eprintln!("could not read input: {e}");
+            return ExitCode::FAILURE;
+        }
+        (_, Err(e)) => {
+            eprintln!("could not read answers: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let puzzle = match aoc_solver::Puzzle::new(&text, year, day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut code = ExitCode::SUCCESS;
+    for part in part.map_or_else(|| puzzle.parts(), |p| vec![p]) {
+        match puzzle.solve(part) {
+            Ok(answer) => {
+                store.record(Entry::new(year, day, part, &user, &key, &answer));
+                println!("Recorded day {day} part {part} for {user}: {answer}");
+            }
+            Err(e) => {
+                eprintln!("Day {day} part {part}:\n{e}");
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Err(e) = store.save(answers) {
+        eprintln!("could not write answers: {e}");
+        return ExitCode::FAILURE;
+    }
+    code
+}
+
+fn generate(year: i32, day: i32, seed: u64, size: usize) -> ExitCode {
+    match aoc_solver::generate(year, day, seed, size) {
+        Some(input) => {
+            println!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("day {day} of {year} is not implemented.");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn shrink(
+    year: i32,
+    day: i32,
+    part: Option<i32>,
+    input: Option<PathBuf>,
+    max_steps: u64,
+) -> ExitCode {
+    let solver = match aoc_solver::solver(year, day) {
+        Some(solver) => solver,
+        None => {
+            eprintln!("day {day} of {year} is not implemented.");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match read_input(input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not read input: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let failure = match part {
+        Some(part) => Failure::Panic(part),
+        None => Failure::Disagreement,
+    };
+    // Every attempt that still fails would print its panic.
+    panic::set_hook(Box::new(|_| {}));
+    let fails = |input: &str| failure.occurs(solver, input, max_steps);
+    if !fails(&input) {
+        eprintln!("the input does not fail that way");
+        return ExitCode::FAILURE;
+    }
+    let shrunk = shrink::shrink(&input, solver.structure(), fails);
+    eprintln!("shrunk {} bytes to {}", input.len(), shrunk.len());
+    print!(
+        "{}",
+        shrink::regression_test(year, day, failure, &shrunk, max_steps)
+    );
+    ExitCode::SUCCESS
+}
+
+fn list(year: i32) -> ExitCode {
+    for info in aoc_solver::puzzles().into_iter().filter(|p| p.year == year) {
+        let join = |parts: &[i32]| {
+            let parts: Vec<String> = parts.iter().map(|p| p.to_string()).collect();
+            parts.join(", ")
+        };
+        print!(
+            "Day {}: {} (parts {}",
+            info.day,
+            info.title,
+            join(&info.parts)
+        );
+        if !info.explain_parts.is_empty() {
+            print!("; explain {}", join(&info.explain_parts));
+        }
+        println!(")");
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let Cli {
+        year,
+        log: log_filter,
+        command,
+    } = Cli::parse();
+    let run = || match command {
+        Command::Solve {
+            day,
+            part,
+            input,
+            limits,
+        } => solve(year, day, part, input, limits),
+        Command::Explain { day, part, input } => explain(year, day, part, input),
+        Command::List => list(year),
+        Command::Identify { input } => identify(input),
+        Command::Check {
+            data,
+            user,
+            answers,
+        } => check(data, user, &answers),
+        Command::Record {
+            day,
+            part,
+            input,
+            user,
+            data,
+            answers,
+        } => record(year, day, part, input, user, data, &answers),
+        Command::Generate { day, seed, size } => generate(year, day, seed, size),
+        Command::Shrink {
+            day,
+            part,
+            input,
+            max_steps,
+        } => shrink(year, day, part, input, max_steps),
+    };
+    match log_filter {
+        Some(filter) => log::with_filter(filter, run),
+        None => run(),
+    }
+}
@@ -0,0 +1,135 @@
+use crate::error::{AoCError, ErrorKind};
+use crate::progress::Progress;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Flag shared between a running solve and whoever may want to stop it.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits how much work a solve may do.
+///
+/// Solvers call [`Budget::step`] in their hot loops and return its error,
+/// which stops the solve once the step limit is spent or the token is
+/// cancelled. What counts as a step is up to each solver, e.g. one move of
+/// a simulation or one candidate tried.
+///
+/// The budget also carries the solve's [`Progress`] reporter, as it is the
+/// one handle every solver is given.
+#[derive(Default)]
+pub struct Budget {
+    max_steps: Option<u64>,
+    used: Cell<u64>,
+    cancel: Option<CancelToken>,
+    progress: Option<Box<dyn Progress>>,
+}
+
+impl Budget {
+    pub fn unlimited() -> Budget {
+        Budget::default()
+    }
+
+    pub fn max_steps(max_steps: u64) -> Budget {
+        Budget {
+            max_steps: Some(max_steps),
+            ..Budget::default()
+        }
+    }
+
+    pub fn with_cancel(mut self, token: CancelToken) -> Budget {
+        self.cancel = Some(token);
+        self
+    }
+
+    pub fn with_progress(mut self, progress: impl Progress + 'static) -> Budget {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Reports progress, if anyone is listening.
+    pub fn progress(&self, fraction: f64, message: Option<&str>) {
+        if let Some(progress) = &self.progress {
+            progress.report(fraction.clamp(0.0, 1.0), message);
+        }
+    }
+
+    /// Steps spent so far.
+    pub fn used(&self) -> u64 {
+        self.used.get()
+    }
+
+    pub fn step(&self) -> Result<(), AoCError> {
+        self.spend(1)
+    }
+
+    pub fn spend(&self, steps: u64) -> Result<(), AoCError> {
+        if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+            return Err(AoCError::new(ErrorKind::Cancelled));
+        }
+        let used = self.used.get().saturating_add(steps);
+        self.used.set(used);
+        match self.max_steps {
+            Some(limit) if used > limit => Err(AoCError::new(ErrorKind::BudgetExceeded { limit })),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Budget, CancelToken};
+    use crate::error::ErrorKind;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_max_steps() {
+        let budget = Budget::max_steps(2);
+        assert!(budget.step().is_ok());
+        assert!(budget.step().is_ok());
+        let error = budget.step().unwrap_err();
+        assert_eq!(error.kind, ErrorKind::BudgetExceeded { limit: 2 });
+        assert_eq!(budget.used(), 3);
+        assert!(Budget::unlimited().spend(u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        let budget = Budget::unlimited().with_cancel(token.clone());
+        assert!(budget.step().is_ok());
+        token.cancel();
+        assert_eq!(budget.step().unwrap_err().kind, ErrorKind::Cancelled);
+    }
+
+    #[test]
+    fn test_progress() {
+        let reports = Rc::new(RefCell::new(Vec::new()));
+        let sink = reports.clone();
+        let budget = Budget::unlimited().with_progress(move |fraction, message: Option<&str>| {
+            sink.borrow_mut()
+                .push((fraction, message.map(str::to_string)))
+        });
+        budget.progress(0.5, Some("halfway"));
+        budget.progress(2.0, None);
+        assert_eq!(
+            *reports.borrow(),
+            vec![(0.5, Some("halfway".to_string())), (1.0, None)]
+        );
+    }
+}
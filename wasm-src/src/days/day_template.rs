@@ -1,71 +1,49 @@
-use derive_more::derive::Display;
-use derive_more::From;
-use nom;
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::AoCError;
+use crate::rng::Rng;
 
 type VerboseNomResult<'a, O> = nom::IResult<&'a str, O, nom::error::VerboseError<&'a str>>;
 
-#[derive(Debug, From, Display)]
-pub enum AoCError<'a> {
-    #[from]
-    // Parsing(nom::Err<NomError<&'a str>>),
-    Parsing(nom::Err<nom::error::VerboseError<&'a str>>),
-}
-
-fn nom_parser(input: &str) -> VerboseNomResult<String> {
-    Ok(("A", "s".to_string()))
-}
+pub struct Solution;
 
-fn part1(input: &str) -> Result<String, AoCError> {
-    return Ok("Part 1 Placeholder".to_string());
+fn nom_parser(input: &str) -> VerboseNomResult<'_, String> {
+    Ok(("", input.to_string()))
 }
 
-fn part2(input: &str) -> Result<String, AoCError> {
-    return Ok("Part 1 Placeholder".to_string());
+/// `size` lines of random numbers.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size).map(|_| rng.range(0, 99).to_string()).collect();
+    lines.join("\n")
 }
 
-pub fn solve(input: &str, part: i32) -> Result<String, String> {
-    let res = match part {
-        1 => part1(input),
-        2 => part2(input),
-        i => return Err(format!("day 1 part {i} is not implemented")),
-    };
-    match res {
-        Ok(v) => Ok(v),
-        Err(AoCError::Parsing(e)) => match e {
-            nom::Err::Error(ve) => Err(nom::error::convert_error(input, ve)),
-            nom::Err::Failure(ve) => Err(nom::error::convert_error(input, ve)),
-            nom::Err::Incomplete(_) => Err("input is imcomplete".to_string()),
-        },
+const EXAMPLE: &str = "\
+";
+
+impl Solver for Solution {
+    type Parsed = String;
+    const TITLE: &'static str = "";
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "Part 1 Placeholder"), (2, "Part 2 Placeholder")],
+    }];
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        let (_, parsed) = nom_parser(input).map_err(|e| AoCError::from_nom(input, e))?;
+        Ok(parsed)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::{part1, part2};
 
-    const TEST_INPUT: &str = "\
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
+    fn part1(_parsed: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(Answer::Text("Part 1 Placeholder".to_string()))
+    }
 
-    #[test]
-    fn test_part1() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), "11")
+    fn part2(_parsed: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(Answer::Text("Part 2 Placeholder".to_string()))
     }
 
-    #[test]
-    fn test_part2() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), "31")
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
     }
 }
@@ -0,0 +1,122 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::AoCError;
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use nom::character::complete::{i32 as str_i32, newline, space1};
+use nom::combinator::{all_consuming, cut};
+use nom::error::VerboseError;
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use nom::IResult as NomResult;
+use std::collections::HashMap;
+
+type VerboseNomResult<'a, O> = NomResult<&'a str, O, VerboseError<&'a str>>;
+
+type LocationIds = Vec<i32>;
+
+pub struct Solution;
+
+fn nom_parser(input: &str) -> VerboseNomResult<'_, Vec<(i32, i32)>> {
+    all_consuming(separated_list0(
+        newline,
+        separated_pair(str_i32, cut(space1), cut(str_i32)),
+    ))(input)
+}
+
+fn parse(input: &str) -> Result<(LocationIds, LocationIds), AoCError> {
+    let (_, pairs) = nom_parser(input).map_err(|e| AoCError::from_nom(input, e))?;
+    let location_id_vectors =
+        pairs
+            .into_iter()
+            .fold((Vec::new(), Vec::new()), |mut vs, (i0, i1)| {
+                vs.0.push(i0);
+                vs.1.push(i1);
+                vs
+            });
+    Ok(location_id_vectors)
+}
+
+fn part1_internal(location_ids_1: &mut [i32], location_ids_2: &mut [i32]) -> i64 {
+    location_ids_1.sort();
+    location_ids_2.sort();
+    location_ids_1
+        .iter()
+        .zip(location_ids_2.iter())
+        .map(|(value1, value2)| (*value1 as i64 - *value2 as i64).abs())
+        .sum()
+}
+
+fn part2_internal(location_ids_1: &[i32], location_ids_2: &[i32]) -> i128 {
+    let v2_counter = location_ids_2
+        .iter()
+        .fold(HashMap::new(), |mut counter, i| {
+            counter.entry(i).and_modify(|v| *v += 1).or_insert(1);
+            counter
+        });
+    location_ids_1
+        .iter()
+        .map(|v| *v as i128 * v2_counter.get(v).copied().unwrap_or(0))
+        .sum()
+}
+
+/// `size` pairs of five digit ids, the right ones often repeating left ones.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<u64> = (0..size).map(|_| rng.range(10_000, 99_999)).collect();
+    let lines: Vec<String> = left
+        .iter()
+        .map(|id| {
+            let right = match rng.chance(0.5) {
+                true => *rng.pick(&left),
+                false => rng.range(10_000, 99_999),
+            };
+            format!("{id}   {right}")
+        })
+        .collect();
+    lines.join("\n")
+}
+
+const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+impl Solver for Solution {
+    type Parsed = (LocationIds, LocationIds);
+    const TITLE: &'static str = "Historian Hysteria";
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "11"), (2, "31")],
+    }];
+    const STRUCTURE: Structure = Structure::Lines(&[' ']);
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        let (mut location_ids_1, mut location_ids_2) = parsed.clone();
+        let distance = part1_internal(&mut location_ids_1, &mut location_ids_2);
+        Ok(distance.into())
+    }
+
+    fn part2(parsed: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        let (location_ids_1, location_ids_2) = parsed;
+        let distance = part2_internal(location_ids_1, location_ids_2);
+        Ok(distance.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn stats((left, _): &Self::Parsed) -> Vec<(&'static str, usize)> {
+        vec![("pairs", left.len())]
+    }
+}
@@ -0,0 +1,120 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::AoCError;
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use itertools::Itertools;
+
+type VerboseNomResult<'a, O> = nom::IResult<&'a str, O, nom::error::VerboseError<&'a str>>;
+type Level = i32;
+type Report = Vec<Level>;
+
+pub struct Solution;
+
+fn nom_parser(input: &str) -> VerboseNomResult<'_, Vec<Report>> {
+    nom::combinator::all_consuming(nom::multi::separated_list1(
+        nom::character::complete::newline,
+        nom::multi::separated_list1(
+            nom::character::complete::space1,
+            nom::character::complete::i32,
+        ),
+    ))(input)
+}
+
+fn is_safe(report: &[Level]) -> bool {
+    let diffs: Vec<i64> = report
+        .iter()
+        .tuple_windows()
+        .map(|(v1, v2)| *v2 as i64 - *v1 as i64)
+        .collect();
+    if !(diffs.iter().all(|diff| *diff > 0) || diffs.iter().all(|diff| *diff < 0)) {
+        return false;
+    }
+    diffs.iter().all(|diff| 1 <= diff.abs() && diff.abs() <= 3)
+}
+
+fn is_safe_2(report: &[Level]) -> bool {
+    for skip_i in 0..report.len() {
+        let diffs: Vec<i64> = report
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != skip_i)
+            .map(|(_, v)| *v as i64)
+            .tuple_windows()
+            .map(|(v1, v2)| v2 - v1)
+            .collect();
+        if (diffs.iter().all(|diff| *diff > 0) || diffs.iter().all(|diff| *diff < 0))
+            && diffs.iter().all(|diff| 1 <= diff.abs() && diff.abs() <= 3)
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// `size` reports of five to eight levels, steadily rising or falling
+/// except for the odd step that is too big or flat.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let reports: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let rising = rng.chance(0.5);
+            let mut level = rng.range(45, 55) as i64;
+            let mut levels = vec![level.to_string()];
+            for _ in 1..rng.range(5, 8) {
+                let step = match rng.chance(0.1) {
+                    true => rng.range(0, 6) as i64,
+                    false => rng.range(1, 3) as i64,
+                };
+                level += if rising { step } else { -step };
+                levels.push(level.to_string());
+            }
+            levels.join(" ")
+        })
+        .collect();
+    reports.join("\n")
+}
+
+const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+impl Solver for Solution {
+    type Parsed = Vec<Report>;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "2"), (2, "4")],
+    }];
+    const STRUCTURE: Structure = Structure::Lines(&[' ']);
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        let (_, reports) = nom_parser(input).map_err(|e| AoCError::from_nom(input, e))?;
+        Ok(reports)
+    }
+
+    fn part1(reports: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        let num_safe = reports.iter().filter(|r| is_safe(r)).count();
+        Ok(num_safe.into())
+    }
+
+    fn part2(reports: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        let num_safe = reports.iter().filter(|r| is_safe_2(r)).count();
+        Ok(num_safe.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn stats(reports: &Self::Parsed) -> Vec<(&'static str, usize)> {
+        let levels = reports.iter().map(Vec::len).sum();
+        vec![("reports", reports.len()), ("levels", levels)]
+    }
+}
@@ -0,0 +1,172 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind, Span};
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use nom::bytes::complete::tag;
+use regex::{Match, Regex};
+
+type VerboseNomResult<'a, O> = nom::IResult<&'a str, O, nom::error::VerboseError<&'a str>>;
+
+pub struct Solution;
+
+fn nom_parser(input: &str) -> VerboseNomResult<'_, (i32, i32)> {
+    nom::sequence::delimited(
+        tag("mul("),
+        nom::sequence::separated_pair(
+            nom::character::complete::i32,
+            tag(","),
+            nom::character::complete::i32,
+        ),
+        tag(")"),
+    )(input)
+}
+
+/// Product of the numbers in a `mul(a,b)` match.
+fn multiply(input: &str, mul: Match) -> Result<i64, AoCError> {
+    let (_, (v1, v2)) = nom_parser(mul.as_str()).map_err(|_| {
+        AoCError::new(ErrorKind::InvalidInput).at(Span::new(input, mul.start(), mul.end()))
+    })?;
+    Ok(v1 as i64 * v2 as i64)
+}
+
+fn part1(input: &str) -> Result<Answer, AoCError> {
+    let pattern = Regex::new(r"(mul\([0-9]{1,3},[0-9]{1,3}\))").unwrap();
+    let mut sum = 0;
+    for m in pattern.find_iter(input) {
+        sum += multiply(input, m)?;
+    }
+    Ok(sum.into())
+}
+
+fn part2(input: &str) -> Result<Answer, AoCError> {
+    let mul_pattern = Regex::new(r"(mul\([0-9]{1,3},[0-9]{1,3}\))").unwrap();
+    let do_pattern = Regex::new(r"(do\(\))").unwrap();
+    let mut do_iter = do_pattern.find_iter(input);
+    let mut mul_iter = mul_pattern.find_iter(input);
+    let no_pattern = Regex::new(r"(don't\(\))").unwrap();
+    let mut no_iter = no_pattern.find_iter(input);
+
+    let mut mul_match = mul_iter.next();
+    let mut do_match = do_iter.next();
+    let mut no_match = no_iter.next();
+
+    crate::debug!(
+        "first do() at {:?}, first don't() at {:?}",
+        do_match,
+        no_match
+    );
+    let mut is_enabled = true;
+
+    let mut sum: i64 = 0;
+    while let Some(mul) = mul_match {
+        let do_start = match do_match {
+            Some(m) => m.start(),
+            None => input.len(),
+        };
+        let no_start = match no_match {
+            Some(m) => m.start(),
+            None => input.len(),
+        };
+        if mul.start() < do_start && mul.start() < no_start {
+            if is_enabled {
+                crate::trace!("{:?}", mul);
+                sum += multiply(input, mul)?;
+            }
+            mul_match = mul_iter.next();
+        } else if do_start < mul.start() && do_start < no_start {
+            crate::debug!("enabled at {}", do_start);
+            is_enabled = true;
+            do_match = do_iter.next();
+        } else if no_start < mul.start() && no_start < do_start {
+            crate::debug!("disabled at {}", no_start);
+            is_enabled = false;
+            no_match = no_iter.next();
+        }
+    }
+    Ok(sum.into())
+}
+
+/// `size` lines of corrupted memory, mixing instructions with broken ones
+/// and junk.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const JUNK: &[&str] = &[
+        "mul",
+        "mul(",
+        "mul[3,7]",
+        "mul(32,64]",
+        "mul ( 2 , 4 )",
+        "mul(1234,5)",
+        "do",
+        "don't",
+        "from()",
+        "what()",
+        "select()",
+        ")",
+        ",",
+        "[",
+        "]",
+        "%",
+        "&",
+        "!",
+        "@",
+        "^",
+        "?",
+        " ",
+        "<",
+        ">",
+        "'",
+    ];
+    let lines: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            (0..rng.range(10, 40))
+                .map(|_| match rng.below(10) {
+                    0..=3 => format!("mul({},{})", rng.range(1, 999), rng.range(1, 999)),
+                    4 => "do()".to_string(),
+                    5 => "don't()".to_string(),
+                    _ => rng.pick(JUNK).to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    lines.join("\n")
+}
+
+impl Solver for Solution {
+    type Parsed = String;
+    const TITLE: &'static str = "Mull It Over";
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example 1",
+            input: "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+            answers: &[(1, "161")],
+        },
+        Example {
+            name: "example 2",
+            input: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+            answers: &[(1, "161"), (2, "48")],
+        },
+    ];
+    const STRUCTURE: Structure = Structure::Chars;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        Ok(input.to_string())
+    }
+
+    fn looks_like(input: &str) -> bool {
+        input.contains("mul(")
+    }
+
+    fn part1(input: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -1,57 +1,15 @@
-use derive_more::derive::Display;
-use derive_more::From;
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind, Span};
+use crate::rng::Rng;
+use crate::shrink::Structure;
 use itertools::Itertools;
 use ndarray::Array2;
 use ndarray::ArrayView2;
-use ndarray::ShapeError;
-use std::fmt;
-
-#[derive(Debug)]
-struct CharLocation {
-    c: char,
-    row: usize,
-    col: usize,
-}
-
-#[derive(Debug, Display)]
-enum Corner {
-    TopLeft,
-    TopRight,
-    BottomRight,
-    BottomLeft,
-}
-
-#[derive(Debug)]
-struct CornerOutOfBounds {
-    row_i: char,
-    col_i: usize,
-    corner: Corner,
-}
-
-impl fmt::Display for CornerOutOfBounds {
-    // This trait requires `fmt` with this exact signature.
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{} corner of {}:{} is out of bounds",
-            self.corner, self.row_i, self.col_i
-        )
-    }
-}
-
-impl fmt::Display for CharLocation {
-    // This trait requires `fmt` with this exact signature.
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "character '{}', found at {}:{}",
-            self.c, self.row, self.col
-        )
-    }
-}
 
 #[derive(Copy, Debug, Clone, PartialEq)]
-enum Item {
+pub enum Item {
     X,
     M,
     A,
@@ -83,18 +41,21 @@ impl<'a, I: Copy> Iterator for RightUpMatrixIter<'a, I> {
         let v = self
             .matrix
             .get((row_root - self.item_index, col_root + self.item_index))
-            .map(|v| *v);
+            .copied();
         self.item_index += 1;
         v
     }
 }
 
-fn iter_right_up<I: Copy>(matrix: ArrayView2<I>, diagonal_index: usize) -> RightUpMatrixIter<I> {
-    return RightUpMatrixIter {
+fn iter_right_up<I: Copy>(
+    matrix: ArrayView2<'_, I>,
+    diagonal_index: usize,
+) -> RightUpMatrixIter<'_, I> {
+    RightUpMatrixIter {
         matrix,
         diagonal_index,
         item_index: 0,
-    };
+    }
 }
 
 struct RightDownMatrixIter<'a, I: Copy> {
@@ -119,106 +80,123 @@ impl<'a, I: Copy> Iterator for RightDownMatrixIter<'a, I> {
         let v = self
             .matrix
             .get((row_root + self.item_index, col_root + self.item_index))
-            .map(|v| *v);
+            .copied();
         self.item_index += 1;
         v
     }
 }
 
 fn iter_right_down<I: Copy>(
-    matrix: ArrayView2<I>,
+    matrix: ArrayView2<'_, I>,
     diagonal_index: usize,
-) -> RightDownMatrixIter<I> {
-    return RightDownMatrixIter {
+) -> RightDownMatrixIter<'_, I> {
+    RightDownMatrixIter {
         matrix,
         diagonal_index,
         item_index: 0,
-    };
+    }
 }
 
-#[derive(Debug, From, Display)]
-pub enum AoCError {
-    UnExpectedCharacter(CharLocation),
-    #[from]
-    ShapeError(ShapeError),
-    MatrxiIndexError(CornerOutOfBounds),
+pub struct Solution;
+
+fn check_row_length(
+    input: &str,
+    row_start: usize,
+    row_end: usize,
+    num_col: Option<usize>,
+    col_i: usize,
+) -> Result<usize, AoCError> {
+    match num_col {
+        Some(expected) if expected != col_i => {
+            Err(AoCError::new(ErrorKind::InconsistentRowLength {
+                expected,
+                found: col_i,
+            })
+            .at(Span::new(input, row_start, row_end)))
+        }
+        _ => Ok(col_i),
+    }
 }
 
 fn parser(input: &str) -> Result<Array2<Item>, AoCError> {
     let mut row_i = 0;
     let mut col_i = 0;
+    let mut num_col = None;
     let mut items = vec![];
-    for c in input.trim().chars() {
+    let offset = input.len() - input.trim_start().len();
+    let mut row_start = offset;
+    for (i, c) in input.trim().char_indices() {
         let item = match c {
             'X' => Item::X,
             'M' => Item::M,
             'A' => Item::A,
             'S' => Item::S,
             '\n' => {
+                num_col = Some(check_row_length(
+                    input,
+                    row_start,
+                    offset + i,
+                    num_col,
+                    col_i,
+                )?);
+                row_start = offset + i + 1;
                 row_i += 1;
                 col_i = 0;
                 continue;
             }
             _ => {
-                return Err(AoCError::UnExpectedCharacter(CharLocation {
-                    c,
-                    row: row_i,
-                    col: col_i,
-                }))
+                return Err(AoCError::unexpected_char(input, offset + i)
+                    .expected("one of 'X', 'M', 'A', 'S'"))
             }
         };
         items.push(item);
         col_i += 1;
     }
-    let num_row = row_i + 1;
-    let num_col = items.len() / num_row;
-    Ok(Array2::from_shape_vec((num_row, num_col), items)?)
+    let row_end = offset + input.trim().len();
+    let num_col = check_row_length(input, row_start, row_end, num_col, col_i)?;
+    Array2::from_shape_vec((row_i + 1, num_col), items)
+        .map_err(|e| AoCError::new(ErrorKind::Other(e.to_string())))
 }
 
 fn is_xmas(items: &(Item, Item, Item, Item)) -> bool {
     *items == (Item::X, Item::M, Item::A, Item::S) || *items == (Item::S, Item::A, Item::M, Item::X)
 }
 
-fn part1(input: &str) -> Result<String, AoCError> {
-    let mat = parser(input)?;
+fn part1(mat: &Array2<Item>) -> usize {
     let mut count = 0;
     for row in mat.rows() {
         count += row
             .iter()
-            .map(|v| *v)
+            .copied()
             .tuple_windows::<(_, _, _, _)>()
-            .filter(|v| is_xmas(v))
+            .filter(is_xmas)
             .count();
     }
     for col in mat.columns() {
         count += col
             .iter()
-            .map(|v| *v)
+            .copied()
             .tuple_windows::<(_, _, _, _)>()
-            .filter(|v| is_xmas(v))
+            .filter(is_xmas)
             .count();
     }
     for diag_i in 0..(mat.nrows() + mat.ncols() - 1) {
-        count += iter_right_up((&mat).into(), diag_i)
+        count += iter_right_up(mat.into(), diag_i)
             .tuple_windows()
-            .map(|v| {
-                dbg!(&v);
-                v
-            })
-            .filter(|v| is_xmas(v))
+            .inspect(|window| crate::trace!("diagonal {diag_i}: {:?}", window))
+            .filter(is_xmas)
             .count();
     }
     for diag_i in 0..(mat.nrows() + mat.ncols() - 1) {
-        count += iter_right_down((&mat).into(), diag_i)
+        count += iter_right_down(mat.into(), diag_i)
             .tuple_windows()
-            .filter(|v| is_xmas(v))
+            .filter(is_xmas)
             .count();
     }
-    return Ok(count.to_string());
+    count
 }
 
-fn part2(input: &str) -> Result<String, AoCError> {
-    let mat = parser(input)?;
+fn part2(mat: &Array2<Item>) -> usize {
     let mut count = 0;
     for ((row_i, col_i), item) in mat.indexed_iter() {
         if col_i == 0 || row_i == 0 {
@@ -230,7 +208,7 @@ fn part2(input: &str) -> Result<String, AoCError> {
         if *item != Item::A {
             continue;
         }
-        let corners = vec![
+        let corners = [
             mat.get((row_i + 1, col_i - 1)).unwrap(),
             mat.get((row_i + 1, col_i + 1)).unwrap(),
             mat.get((row_i - 1, col_i + 1)).unwrap(),
@@ -250,27 +228,23 @@ fn part2(input: &str) -> Result<String, AoCError> {
         }
         count += 1;
     }
-
-    return Ok(count.to_string());
+    count
 }
 
-pub fn solve(input: &str, part: i32) -> Result<String, String> {
-    let res = match part {
-        1 => part1(input),
-        2 => part2(input),
-        i => return Err(format!("day 1 part {i} is not implemented")),
-    };
-    match res {
-        Ok(v) => Ok(v),
-        Err(e) => Err(format!("{}", e)),
-    }
+/// A `size` by `size` grid of random letters of XMAS.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *rng.pick(&['X', 'M', 'A', 'S']))
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{iter_right_down, iter_right_up, parser, part1, part2};
-
-    const TEST_INPUT: &str = "\
+const EXAMPLE: &str = "\
 MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
@@ -282,9 +256,44 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX";
 
+impl Solver for Solution {
+    type Parsed = Array2<Item>;
+    const TITLE: &'static str = "Ceres Search";
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "18"), (2, "9")],
+    }];
+    const STRUCTURE: Structure = Structure::Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        parser(input)
+    }
+
+    fn part1(mat: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part1(mat).into())
+    }
+
+    fn part2(mat: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part2(mat).into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn stats(mat: &Self::Parsed) -> Vec<(&'static str, usize)> {
+        vec![("rows", mat.nrows()), ("columns", mat.ncols())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EXAMPLE;
+
     #[test]
     fn test_right_up() {
-        let m = super::parser(TEST_INPUT).unwrap();
+        let m = super::parser(EXAMPLE).unwrap();
         let mut diter = super::iter_right_up((&m).into(), 0);
         assert_eq!(Some(super::Item::M), diter.next());
         assert_eq!(None, diter.next());
@@ -320,7 +329,7 @@ MXMXAXMASX";
     }
     #[test]
     fn test_right_down() {
-        let m = super::parser(TEST_INPUT).unwrap();
+        let m = super::parser(EXAMPLE).unwrap();
         let mut diter = super::iter_right_down((&m).into(), 0);
         assert_eq!(Some(super::Item::M), diter.next());
         assert_eq!(None, diter.next());
@@ -356,18 +365,4 @@ MXMXAXMASX";
         let mut diter = super::iter_right_down((&m).into(), 117);
         assert_eq!(None, diter.next());
     }
-
-    #[test]
-    fn test_part1() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), "18")
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), "9")
-    }
 }
@@ -0,0 +1,267 @@
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind};
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use nom::bytes::complete::tag;
+
+type VerboseNomResult<'a, O> = nom::IResult<&'a str, O, nom::error::VerboseError<&'a str>>;
+
+type PageRule = (i32, i32);
+
+pub struct Solution;
+
+fn nom_parser(input: &str) -> VerboseNomResult<'_, (Vec<PageRule>, Vec<Vec<i32>>)> {
+    nom::combinator::all_consuming(nom::sequence::separated_pair(
+        nom::multi::separated_list1(
+            tag("\n"),
+            nom::sequence::separated_pair(
+                nom::character::complete::i32,
+                nom::combinator::cut(nom::error::context("'|'", tag("|"))),
+                nom::combinator::cut(nom::character::complete::i32),
+            ),
+        ),
+        tag("\n\n"),
+        nom::multi::separated_list1(
+            tag("\n"),
+            nom::multi::separated_list1(tag(","), nom::character::complete::i32),
+        ),
+    ))(input)
+}
+
+/// The middle page of an update.
+fn middle(updates: &[i32]) -> Result<i64, AoCError> {
+    updates
+        .get(updates.len() / 2)
+        .map(|page| *page as i64)
+        .ok_or_else(|| AoCError::new(ErrorKind::Other("update has no pages".to_string())))
+}
+
+fn part1(rules: &[PageRule], update_lists: &[Vec<i32>]) -> Result<i64, AoCError> {
+    let mut score = 0;
+    for updates in update_lists.iter() {
+        let mut is_ordered = true;
+        for rule in rules.iter() {
+            let pre = match updates.iter().position(|v| *v == rule.0) {
+                Some(p) => p,
+                None => continue,
+            };
+            let post = match updates.iter().position(|v| *v == rule.1) {
+                Some(p) => p,
+                None => continue,
+            };
+            if pre > post {
+                is_ordered = false;
+                break;
+            }
+        }
+        if is_ordered {
+            score += middle(updates)?;
+        }
+    }
+    Ok(score)
+}
+
+fn part2(rules: &[PageRule], update_lists: &[Vec<i32>]) -> Result<i64, AoCError> {
+    let mut score = 0;
+    for updates in update_lists.iter() {
+        let mut is_ordered = true;
+        for rule in rules.iter() {
+            let pre = match updates.iter().position(|v| *v == rule.0) {
+                Some(p) => p,
+                None => continue,
+            };
+            let post = match updates.iter().position(|v| *v == rule.1) {
+                Some(p) => p,
+                None => continue,
+            };
+            if pre > post {
+                is_ordered = false;
+                break;
+            }
+        }
+        if is_ordered {
+            continue;
+        }
+        let mut ordered_update: Vec<i32> = vec![];
+        for update in updates.iter() {
+            let mut start = 0;
+            let mut end = ordered_update.len();
+            for (pre, post) in rules.iter() {
+                if update == pre {
+                    end = match ordered_update.iter().position(|v| v == post) {
+                        Some(post_pos) => min(post_pos, end),
+                        None => end,
+                    };
+                } else if update == post {
+                    start = match ordered_update.iter().position(|v| v == pre) {
+                        Some(pre_pos) => max(pre_pos + 1, start),
+                        None => start,
+                    };
+                }
+            }
+            ordered_update.insert(start, *update);
+        }
+        score += middle(&ordered_update)?;
+    }
+    Ok(score)
+}
+
+/// Whether no rule puts a later page of the update before an earlier one,
+/// checking every pair of pages.
+fn reference_ordered(rules: &HashSet<PageRule>, update: &[i32]) -> bool {
+    update.iter().enumerate().all(|(i, earlier)| {
+        update[i + 1..]
+            .iter()
+            .all(|later| !rules.contains(&(*later, *earlier)))
+    })
+}
+
+/// Sorts the update by repeatedly taking a page that no other remaining
+/// page must come before.
+fn reference_sort(
+    rules: &HashSet<PageRule>,
+    update: &[i32],
+    budget: &Budget,
+) -> Result<Vec<i32>, AoCError> {
+    let mut remaining = update.to_vec();
+    let mut sorted = Vec::with_capacity(update.len());
+    while !remaining.is_empty() {
+        budget.spend(remaining.len() as u64)?;
+        let first = remaining
+            .iter()
+            .position(|page| {
+                !remaining
+                    .iter()
+                    .any(|other| rules.contains(&(*other, *page)))
+            })
+            .ok_or_else(|| {
+                AoCError::new(ErrorKind::Other(
+                    "the rules for an update form a cycle".to_string(),
+                ))
+            })?;
+        sorted.push(remaining.remove(first));
+    }
+    Ok(sorted)
+}
+
+fn reference(
+    rules: &[PageRule],
+    update_lists: &[Vec<i32>],
+    part: i32,
+    budget: &Budget,
+) -> Result<i64, AoCError> {
+    let rules: HashSet<PageRule> = rules.iter().copied().collect();
+    let mut score = 0;
+    for update in update_lists.iter() {
+        match (part, reference_ordered(&rules, update)) {
+            (1, true) => score += middle(update)?,
+            (2, false) => score += middle(&reference_sort(&rules, update, budget)?)?,
+            _ => {}
+        }
+    }
+    Ok(score)
+}
+
+/// Rules ordering every pair of up to 90 pages, then `size` updates of
+/// some of the pages, about half of them in order.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<u64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate((size + 2).min(pages.len()));
+    let mut rules = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{before}|{after}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let updates: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(rng.below(pages.len().div_ceil(2)) * 2 + 1);
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|p| p == page));
+            }
+            let update: Vec<String> = update.iter().map(u64::to_string).collect();
+            update.join(",")
+        })
+        .collect();
+    format!("{}\n\n{}", rules.join("\n"), updates.join("\n"))
+}
+
+const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+impl Solver for Solution {
+    type Parsed = (Vec<PageRule>, Vec<Vec<i32>>);
+    const TITLE: &'static str = "Print Queue";
+    const REFERENCE_PARTS: &'static [i32] = &[1, 2];
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "143"), (2, "123")],
+    }];
+    const STRUCTURE: Structure = Structure::Lines(&[',']);
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        let (_, parsed) = nom_parser(input).map_err(|e| AoCError::from_nom(input, e))?;
+        Ok(parsed)
+    }
+
+    fn part1((rules, update_lists): &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part1(rules, update_lists)?.into())
+    }
+
+    fn part2((rules, update_lists): &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part2(rules, update_lists)?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn reference(
+        (rules, update_lists): &Self::Parsed,
+        part: i32,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError> {
+        Ok(reference(rules, update_lists, part, budget)?.into())
+    }
+
+    fn stats((rules, update_lists): &Self::Parsed) -> Vec<(&'static str, usize)> {
+        vec![("rules", rules.len()), ("updates", update_lists.len())]
+    }
+}
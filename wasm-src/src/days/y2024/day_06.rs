@@ -0,0 +1,349 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind, Span};
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use derive_more::derive::Display;
+use std::cmp::max;
+use std::collections::HashSet;
+
+type Position = (i32, i32);
+
+pub struct Solution;
+
+fn parser(input: &str) -> Result<(HashSet<Position>, Position, Position), AoCError> {
+    let mut row_i: i32 = 0;
+    let mut col_i: i32 = 0;
+    let mut max_col: i32 = 0;
+    let mut start_position: Option<Position> = None;
+    let mut occupied_positions: HashSet<Position> = HashSet::new();
+
+    let offset = input.len() - input.trim_start().len();
+    for (i, c) in input.trim().char_indices() {
+        let item = match c {
+            '.' => None,
+            '#' => Some((row_i, col_i)),
+            '^' => {
+                start_position = match start_position {
+                    Some(p) => {
+                        let first = (p.0 as usize + 1, p.1 as usize + 1);
+                        return Err(AoCError::new(ErrorKind::MultipleStartPositions { first })
+                            .at(Span::char_at(input, offset + i)));
+                    }
+                    None => Some((row_i, col_i)),
+                };
+                None
+            }
+            '\n' => {
+                row_i += 1;
+                max_col = max(max_col, col_i - 1);
+                col_i = 0;
+                continue;
+            }
+            _ => {
+                return Err(AoCError::unexpected_char(input, offset + i)
+                    .expected("one of '.', '#', '^', '\\n'"))
+            }
+        };
+        if let Some(pos) = item {
+            occupied_positions.insert(pos);
+        }
+        col_i += 1;
+    }
+    max_col = max(max_col, col_i - 1);
+    let board_size = (row_i + 1, max_col + 1);
+    let start_position = match start_position {
+        Some(pos) => pos,
+        None => return Err(AoCError::new(ErrorKind::NoStartPosition)),
+    };
+    Ok((occupied_positions, start_position, board_size))
+}
+
+#[derive(Debug, Display, Hash, PartialEq, Eq, Clone, Copy)]
+enum Direction {
+    N,
+    E,
+    S,
+    W,
+}
+
+fn part1(
+    occupied_positions: &HashSet<Position>,
+    start_position: Position,
+    board_size: Position,
+    budget: &Budget,
+) -> Result<usize, AoCError> {
+    let mut visited: HashSet<Position> = HashSet::new();
+    let mut visited_states: HashSet<(Position, Direction)> = HashSet::new();
+    let mut direction = Direction::N;
+    let mut current_pos = start_position;
+    loop {
+        if current_pos.0 < 0 || board_size.0 <= current_pos.0 {
+            break;
+        }
+        if current_pos.1 < 0 || board_size.1 <= current_pos.1 {
+            break;
+        }
+        budget.step()?;
+        if !visited_states.insert((current_pos, direction)) {
+            return Err(AoCError::new(ErrorKind::GuardNeverLeaves));
+        }
+        visited.insert(current_pos);
+        let next_pos = match direction {
+            Direction::N => (current_pos.0 - 1, current_pos.1),
+            Direction::E => (current_pos.0, current_pos.1 + 1),
+            Direction::S => (current_pos.0 + 1, current_pos.1),
+            Direction::W => (current_pos.0, current_pos.1 - 1),
+        };
+        if occupied_positions.contains(&next_pos) {
+            direction = match direction {
+                Direction::N => Direction::E,
+                Direction::E => Direction::S,
+                Direction::S => Direction::W,
+                Direction::W => Direction::N,
+            };
+            continue;
+        }
+        current_pos = next_pos;
+    }
+
+    Ok(visited.len())
+}
+
+fn part2(
+    mut occupied_positions: HashSet<Position>,
+    start_position: Position,
+    board_size: Position,
+    budget: &Budget,
+) -> Result<usize, AoCError> {
+    let mut num_loops = 0;
+    for obs_row_i in 0..board_size.0 {
+        budget.progress(
+            obs_row_i as f64 / board_size.0 as f64,
+            Some(&format!(
+                "obstacles in row {} of {}",
+                obs_row_i + 1,
+                board_size.0
+            )),
+        );
+        for obs_col_i in 0..board_size.1 {
+            let obs_pos = (obs_row_i, obs_col_i);
+            if obs_pos == start_position {
+                continue;
+            }
+            if occupied_positions.contains(&obs_pos) {
+                continue;
+            }
+            occupied_positions.insert(obs_pos);
+            let mut visited_states: HashSet<(Position, Direction)> = HashSet::new();
+            let mut direction = Direction::N;
+            let mut current_pos = start_position;
+            let mut created_loop = false;
+            loop {
+                budget.step()?;
+                crate::trace!(
+                    "obstacle {:?}: guard at {:?} facing {}",
+                    obs_pos,
+                    current_pos,
+                    direction
+                );
+                if current_pos.0 < 0 || board_size.0 <= current_pos.0 {
+                    break;
+                }
+                if current_pos.1 < 0 || board_size.1 <= current_pos.1 {
+                    break;
+                }
+                if !visited_states.insert((current_pos, direction)) {
+                    created_loop = true;
+                    break;
+                }
+                let next_pos = match direction {
+                    Direction::N => (current_pos.0 - 1, current_pos.1),
+                    Direction::E => (current_pos.0, current_pos.1 + 1),
+                    Direction::S => (current_pos.0 + 1, current_pos.1),
+                    Direction::W => (current_pos.0, current_pos.1 - 1),
+                };
+                if occupied_positions.contains(&next_pos) {
+                    direction = match direction {
+                        Direction::N => Direction::E,
+                        Direction::E => Direction::S,
+                        Direction::S => Direction::W,
+                        Direction::W => Direction::N,
+                    };
+                    continue;
+                }
+                current_pos = next_pos;
+            }
+            if created_loop {
+                num_loops += 1;
+            }
+
+            occupied_positions.remove(&obs_pos);
+        }
+    }
+    budget.progress(1.0, None);
+    Ok(num_loops)
+}
+
+/// The map as parsed, with the obstacles and the guard's start.
+fn visualize(
+    occupied_positions: &HashSet<Position>,
+    start_position: Position,
+    board_size: Position,
+) -> String {
+    let rows: Vec<String> = (0..board_size.0)
+        .map(|row| {
+            (0..board_size.1)
+                .map(|col| match (row, col) {
+                    pos if pos == start_position => '^',
+                    pos if occupied_positions.contains(&pos) => '#',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+/// A `size` by `size` map with about one cell in ten blocked, on which
+/// the guard leaves the map.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut density = 0.1;
+    loop {
+        let mut rows: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(density) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        rows[rng.below(size)][rng.below(size)] = '^';
+        let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+        let input = rows.join("\n");
+        if let Ok((obstacles, start, board_size)) = parser(&input) {
+            if part1(&obstacles, start, board_size, &Budget::unlimited()).is_ok() {
+                return input;
+            }
+        }
+        // Fewer obstacles make loops rarer, and none make them impossible.
+        density = if density < 0.01 { 0.0 } else { density / 2.0 };
+    }
+}
+
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+impl Solver for Solution {
+    type Parsed = (HashSet<Position>, Position, Position);
+    const TITLE: &'static str = "Guard Gallivant";
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "41"), (2, "6")],
+    }];
+    const STRUCTURE: Structure = Structure::Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        parser(input)
+    }
+
+    fn part1(
+        (occupied_positions, start_position, board_size): &Self::Parsed,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError> {
+        Ok(part1(occupied_positions, *start_position, *board_size, budget)?.into())
+    }
+
+    fn part2(
+        (occupied_positions, start_position, board_size): &Self::Parsed,
+        budget: &Budget,
+    ) -> Result<Answer, AoCError> {
+        Ok(part2(
+            occupied_positions.clone(),
+            *start_position,
+            *board_size,
+            budget,
+        )?
+        .into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn stats(
+        (occupied_positions, _, board_size): &Self::Parsed,
+    ) -> Vec<(&'static str, usize)> {
+        vec![
+            ("rows", board_size.0 as usize),
+            ("columns", board_size.1 as usize),
+            ("obstacles", occupied_positions.len()),
+        ]
+    }
+
+    fn visualize(
+        (occupied_positions, start_position, board_size): &Self::Parsed,
+    ) -> Option<String> {
+        Some(visualize(occupied_positions, *start_position, *board_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Solution, EXAMPLE};
+    use crate::aoc_solver::{DynSolver, Solver};
+    use crate::budget::Budget;
+    use crate::error::ErrorKind;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_progress() {
+        let fractions = Rc::new(RefCell::new(Vec::new()));
+        let sink = fractions.clone();
+        let budget = Budget::unlimited()
+            .with_progress(move |fraction, _: Option<&str>| sink.borrow_mut().push(fraction));
+        assert!(Solution.solve_with_budget(EXAMPLE, 2, &budget).is_ok());
+        let fractions = fractions.borrow();
+        assert_eq!(fractions.len(), 11);
+        assert!(fractions.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(fractions.last(), Some(&1.0));
+    }
+
+    #[test]
+    fn test_budget() {
+        let result = Solution.solve_with_budget(EXAMPLE, 2, &Budget::max_steps(1000));
+        assert_eq!(
+            result.unwrap_err().kind,
+            ErrorKind::BudgetExceeded { limit: 1000 }
+        );
+    }
+
+    #[test]
+    fn test_guard_never_leaves() {
+        let looping = ".#..\n...#\n#^..\n..#.";
+        let result = Solution.solve(looping, 1);
+        assert_eq!(result.unwrap_err().kind, ErrorKind::GuardNeverLeaves);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let parsed = <Solution as Solver>::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::visualize(&parsed).unwrap(), EXAMPLE);
+        assert_eq!(
+            Solution::stats(&parsed),
+            vec![("rows", 10), ("columns", 10), ("obstacles", 8)]
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind};
+use crate::normalize::{Normalize, Tabs};
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use derive_more::derive::Display;
+use nom::bytes::complete::tag;
+use nom::combinator::{all_consuming, cut};
+use nom::error::context;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use std::convert::TryFrom;
+
+type VerboseNomResult<'a, O> = nom::IResult<&'a str, O, nom::error::VerboseError<&'a str>>;
+
+type Equation = (u64, Vec<u64>);
+
+pub struct Solution;
+
+fn nom_parser(input: &str) -> VerboseNomResult<'_, Vec<Equation>> {
+    all_consuming(separated_list1(
+        tag("\n"),
+        separated_pair(
+            nom::character::complete::u64,
+            cut(context("': '", tag(": "))),
+            cut(separated_list1(tag(" "), nom::character::complete::u64)),
+        ),
+    ))(input)
+}
+
+#[derive(PartialEq, Eq, Debug, Display)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    /// Every combination of `num_ops` operators, `None` if there are more
+    /// than a `u64` can count.
+    fn vec_iter(num_ops: u32) -> Option<OpIter> {
+        Some(OpIter {
+            i: 0,
+            end: 2u64.checked_pow(num_ops)?,
+            num_ops,
+        })
+    }
+}
+
+struct OpIter {
+    i: u64,
+    end: u64,
+    num_ops: u32,
+}
+
+impl Iterator for OpIter {
+    type Item = Vec<Op>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.end {
+            return None;
+        }
+        let ops = (0..self.num_ops)
+            .map(|op_i| match (self.i >> op_i) & 1 {
+                0 => Op::Add,
+                _ => Op::Mul,
+            })
+            .collect();
+        self.i += 1;
+        Some(ops)
+    }
+}
+
+fn too_many_numbers(numbers: &[u64]) -> AoCError {
+    AoCError::new(ErrorKind::Other(format!(
+        "an equation with {} numbers has too many operator combinations",
+        numbers.len()
+    )))
+}
+
+/// `a` followed by the digits of `b`, `None` if that overflows.
+fn concat(a: u64, b: u64) -> Option<u64> {
+    let mut shift: u64 = 10;
+    while shift <= b {
+        shift = shift.checked_mul(10)?;
+    }
+    a.checked_mul(shift)?.checked_add(b)
+}
+
+/// Combinations whose intermediate results overflow are skipped.
+fn part1(equations: &[Equation], budget: &Budget) -> Result<i128, AoCError> {
+    let mut calibration = 0;
+    for (target, numbers) in equations {
+        let (first, rest) = match numbers.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+        let op_iter = u32::try_from(rest.len())
+            .ok()
+            .and_then(Op::vec_iter)
+            .ok_or_else(|| too_many_numbers(numbers))?;
+        for ops in op_iter {
+            budget.step()?;
+            let res = ops
+                .iter()
+                .zip(rest)
+                .try_fold(*first, |res, (op, num)| match op {
+                    Op::Add => res.checked_add(*num),
+                    Op::Mul => res.checked_mul(*num),
+                });
+            if res == Some(*target) {
+                calibration += *target as i128;
+                break;
+            }
+        }
+    }
+    Ok(calibration)
+}
+
+#[derive(PartialEq, Eq, Debug, Display)]
+enum Op3 {
+    Add,
+    Mul,
+    Con,
+}
+
+impl Op3 {
+    /// Every combination of `num_ops` operators, `None` if there are more
+    /// than a `u64` can count.
+    fn vec_iter(num_ops: u32) -> Option<Op3Iter> {
+        Some(Op3Iter {
+            i: 0,
+            end: 3u64.checked_pow(num_ops)?,
+            num_ops,
+        })
+    }
+}
+
+struct Op3Iter {
+    i: u64,
+    end: u64,
+    num_ops: u32,
+}
+
+impl Iterator for Op3Iter {
+    type Item = Vec<Op3>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.end {
+            return None;
+        }
+        let mut rest = self.i;
+        let ops = (0..self.num_ops)
+            .map(|_| {
+                let op = match rest % 3 {
+                    0 => Op3::Add,
+                    1 => Op3::Mul,
+                    _ => Op3::Con,
+                };
+                rest /= 3;
+                op
+            })
+            .collect();
+        self.i += 1;
+        Some(ops)
+    }
+}
+
+fn part2(equations: &[Equation], budget: &Budget) -> Result<i128, AoCError> {
+    let mut calibration = 0;
+    for (i, (target, numbers)) in equations.iter().enumerate() {
+        budget.progress(
+            i as f64 / equations.len() as f64,
+            Some(&format!("equation {} of {}", i + 1, equations.len())),
+        );
+        let (first, rest) = match numbers.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+        let op_iter = u32::try_from(rest.len())
+            .ok()
+            .and_then(Op3::vec_iter)
+            .ok_or_else(|| too_many_numbers(numbers))?;
+        // Results never shrink, so those past the target are dead ends,
+        // unless a later 0 multiplies them back down.
+        let prune = !rest.contains(&0);
+        for ops in op_iter {
+            budget.step()?;
+            let res = ops.iter().zip(rest).try_fold(*first, |res, (op, num)| {
+                match op {
+                    Op3::Add => res.checked_add(*num),
+                    Op3::Mul => res.checked_mul(*num),
+                    Op3::Con => concat(res, *num),
+                }
+                .filter(|res| !prune || res <= target)
+            });
+            if res == Some(*target) {
+                calibration += *target as i128;
+                break;
+            }
+        }
+    }
+    budget.progress(1.0, None);
+    Ok(calibration)
+}
+
+/// Whether some operators make `value` followed by `rest` evaluate to
+/// `target`, trying every combination. Overflowing results are dead ends.
+fn reference_solvable(
+    target: u64,
+    value: u64,
+    rest: &[u64],
+    ops: &[fn(u64, u64) -> Option<u64>],
+    budget: &Budget,
+) -> Result<bool, AoCError> {
+    budget.step()?;
+    let (next, rest) = match rest.split_first() {
+        Some(split) => split,
+        None => return Ok(value == target),
+    };
+    for op in ops {
+        if let Some(value) = op(value, *next) {
+            if reference_solvable(target, value, rest, ops, budget)? {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn reference(equations: &[Equation], part: i32, budget: &Budget) -> Result<i128, AoCError> {
+    let ops: &[fn(u64, u64) -> Option<u64>] = match part {
+        1 => &[u64::checked_add, u64::checked_mul],
+        _ => &[u64::checked_add, u64::checked_mul, concat],
+    };
+    let mut calibration = 0;
+    for (target, numbers) in equations {
+        if let Some((first, rest)) = numbers.split_first() {
+            if reference_solvable(*target, *first, rest, ops, budget)? {
+                calibration += *target as i128;
+            }
+        }
+    }
+    Ok(calibration)
+}
+
+/// `size` equations of two to eight numbers, a few of them 0, half of the
+/// equations solvable with some operators.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let equations: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let numbers: Vec<u64> = (0..rng.range(2, 8))
+                .map(|_| match rng.chance(0.1) {
+                    true => 0,
+                    false => rng.range(1, 99),
+                })
+                .collect();
+            let solved = numbers[1..]
+                .iter()
+                .try_fold(numbers[0], |value, n| match rng.below(3) {
+                    0 => value.checked_add(*n),
+                    1 => value.checked_mul(*n),
+                    _ => concat(value, *n),
+                });
+            let target = match solved {
+                Some(target) if rng.chance(0.5) => target,
+                _ => rng.range(1, 100_000),
+            };
+            let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+            format!("{target}: {}", numbers.join(" "))
+        })
+        .collect();
+    equations.join("\n")
+}
+
+const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+impl Solver for Solution {
+    type Parsed = Vec<Equation>;
+    const TITLE: &'static str = "Bridge Repair";
+    const REFERENCE_PARTS: &'static [i32] = &[1, 2];
+    const NORMALIZE: Normalize = Normalize {
+        tabs: Tabs::ToSpace,
+        ..Normalize::DEFAULT
+    };
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "3749"), (2, "11387")],
+    }];
+    const STRUCTURE: Structure = Structure::Lines(&[' ']);
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        let (_, equations) = nom_parser(input).map_err(|e| AoCError::from_nom(input, e))?;
+        Ok(equations)
+    }
+
+    fn part1(equations: &Self::Parsed, budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part1(equations, budget)?.into())
+    }
+
+    fn part2(equations: &Self::Parsed, budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part2(equations, budget)?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn reference(equations: &Self::Parsed, part: i32, budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(reference(equations, part, budget)?.into())
+    }
+
+    fn stats(equations: &Self::Parsed) -> Vec<(&'static str, usize)> {
+        let numbers = equations.iter().map(|(_, numbers)| numbers.len()).sum();
+        vec![("equations", equations.len()), ("numbers", numbers)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::answer::Answer;
+    use crate::aoc_solver::DynSolver;
+
+    #[test]
+    fn test_tabs_and_trailing_newline() {
+        let result = Solution.solve("190:\t10\t19\r\n83: 17 5\r\n", 1);
+        assert_eq!(result, Ok(Answer::Integer(190)))
+    }
+
+    #[test]
+    fn test_zero_after_target_passed() {
+        assert_eq!(Solution.solve("5: 3 4 0 5", 2), Ok(Answer::Integer(5)));
+    }
+}
+#[test]
+fn test_op_iter() {
+    let mut op_iter = Op::vec_iter(3).unwrap();
+    assert_eq!(op_iter.next(), Some(vec![Op::Add, Op::Add, Op::Add]));
+    assert_eq!(op_iter.next(), Some(vec![Op::Mul, Op::Add, Op::Add]));
+    assert_eq!(op_iter.next(), Some(vec![Op::Add, Op::Mul, Op::Add]));
+    assert_eq!(op_iter.next(), Some(vec![Op::Mul, Op::Mul, Op::Add]));
+    assert_eq!(op_iter.count(), 2_usize.pow(3) - 4);
+    assert!(Op::vec_iter(64).is_none());
+}
@@ -0,0 +1,321 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind};
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use std::cmp::max;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type Pos = (usize, usize);
+type AntennaMap = HashMap<char, Vec<Pos>>;
+
+pub struct Solution;
+
+fn parse(input: &str) -> Result<(AntennaMap, Pos), AoCError> {
+    let mut antenna_map: AntennaMap = HashMap::new();
+    let mut row_i = 0;
+    let mut max_col = 0;
+    let mut col_i = 0;
+    for c in input.trim().chars() {
+        match c {
+            '.' => (),
+            '\n' => {
+                col_i = 0;
+                row_i += 1;
+                continue;
+            }
+            antenna_c => match antenna_map.get_mut(&antenna_c) {
+                Some(pos) => pos.push((row_i, col_i)),
+                None => {
+                    antenna_map.insert(antenna_c, vec![(row_i, col_i)]);
+                }
+            },
+        }
+        max_col = max(max_col, col_i);
+        col_i += 1;
+    }
+
+    Ok((antenna_map, (row_i + 1, max_col + 1)))
+}
+
+fn get_antinodes(a1: Pos, a2: Pos, board_size: Pos) -> Option<Pos> {
+    if 2 * a1.0 < a2.0 || 2 * a1.1 < a2.1 {
+        None
+    } else {
+        let a = (2 * a1.0 - a2.0, 2 * a1.1 - a2.1);
+        if a.0 >= board_size.0 || a.1 >= board_size.1 {
+            None
+        } else {
+            Some(a)
+        }
+    }
+}
+
+fn get_resonant_antinodes(a1: Pos, a2: Pos, board_size: Pos) -> Vec<Pos> {
+    // firs
+    let mut n1 = a1;
+    let mut n2 = a2;
+    let mut antinodes: Vec<Pos> = vec![n1, n2];
+    loop {
+        let n3 = if 2 * n2.0 < n1.0 || 2 * n2.1 < n1.1 {
+            break;
+        } else {
+            let a = (2 * n2.0 - n1.0, 2 * n2.1 - n1.1);
+            if a.0 >= board_size.0 || a.1 >= board_size.1 {
+                break;
+            } else {
+                a
+            }
+        };
+        antinodes.push(n3);
+        n1 = n2;
+        n2 = n3;
+    }
+    antinodes
+}
+
+/// The map as parsed, with every antenna at its position.
+fn visualize(antenna_map: &AntennaMap, board_size: Pos) -> String {
+    let mut grid = vec![vec!['.'; board_size.1]; board_size.0];
+    for (&frequency, positions) in antenna_map {
+        for &(row, col) in positions {
+            grid[row][col] = frequency;
+        }
+    }
+    let rows: Vec<String> = grid.iter().map(|row| row.iter().collect()).collect();
+    rows.join("\n")
+}
+
+fn explain_part1((antenna_map, board_size): &(AntennaMap, Pos)) -> String {
+    let board_size = *board_size;
+    let mut s = String::new();
+    s.push_str(&format!(
+        "Board Size: ({}, {})\n",
+        board_size.0, board_size.1
+    ));
+
+    let mut occupied_pos: HashSet<Pos> = HashSet::new();
+    for antenna_pos in antenna_map.values() {
+        for a1_pos in antenna_pos.iter() {
+            for a2_pos in antenna_pos.iter() {
+                if a1_pos == a2_pos {
+                    continue;
+                }
+                if let Some(pos) = get_antinodes(*a1_pos, *a2_pos, board_size) {
+                    occupied_pos.insert(pos);
+                }
+                if let Some(pos) = get_antinodes(*a2_pos, *a1_pos, board_size) {
+                    occupied_pos.insert(pos);
+                }
+            }
+        }
+    }
+    let mut num = 0;
+    for row_i in 0..board_size.0 {
+        for col_i in 0..board_size.1 {
+            if occupied_pos.contains(&(row_i, col_i)) {
+                s.push('#');
+                num += 1;
+            } else {
+                s.push('.');
+            }
+        }
+        s.push('\n')
+    }
+    s.push_str(&format!("Num matches: {}", num));
+    s
+}
+
+fn explain_part2((antenna_map, board_size): &(AntennaMap, Pos)) -> String {
+    let board_size = *board_size;
+    let mut s = String::new();
+    s.push_str(&format!(
+        "Board Size: ({}, {})\n",
+        board_size.0, board_size.1
+    ));
+
+    let mut occupied_pos: HashSet<Pos> = HashSet::new();
+    for antenna_pos in antenna_map.values() {
+        for a1_pos in antenna_pos.iter() {
+            for a2_pos in antenna_pos.iter() {
+                if a1_pos == a2_pos {
+                    continue;
+                }
+                for antinode in get_resonant_antinodes(*a1_pos, *a2_pos, board_size).iter() {
+                    occupied_pos.insert(*antinode);
+                }
+                for antinode in get_resonant_antinodes(*a2_pos, *a1_pos, board_size).iter() {
+                    occupied_pos.insert(*antinode);
+                }
+            }
+        }
+    }
+    let mut num = 0;
+    for row_i in 0..board_size.0 {
+        for col_i in 0..board_size.1 {
+            if occupied_pos.contains(&(row_i, col_i)) {
+                s.push('#');
+                num += 1;
+            } else {
+                s.push('.');
+            }
+        }
+        s.push('\n')
+    }
+    s.push_str(&format!("Num matches: {}", num));
+    s
+}
+fn part1(
+    (antenna_map, board_size): &(AntennaMap, Pos),
+    budget: &Budget,
+) -> Result<usize, AoCError> {
+    let board_size = *board_size;
+    let mut occupied_pos: HashSet<Pos> = HashSet::new();
+    for antenna_pos in antenna_map.values() {
+        for a1_pos in antenna_pos.iter() {
+            for a2_pos in antenna_pos.iter() {
+                if a1_pos == a2_pos {
+                    break;
+                }
+                budget.step()?;
+                if let Some(pos) = get_antinodes(*a1_pos, *a2_pos, board_size) {
+                    occupied_pos.insert(pos);
+                }
+                if let Some(pos) = get_antinodes(*a2_pos, *a1_pos, board_size) {
+                    occupied_pos.insert(pos);
+                }
+            }
+        }
+    }
+    Ok(occupied_pos.len())
+}
+
+fn part2(
+    (antenna_map, board_size): &(AntennaMap, Pos),
+    budget: &Budget,
+) -> Result<usize, AoCError> {
+    let board_size = *board_size;
+    let mut occupied_pos: HashSet<Pos> = HashSet::new();
+    for antenna_pos in antenna_map.values() {
+        for a1_pos in antenna_pos.iter() {
+            for a2_pos in antenna_pos.iter() {
+                if a1_pos == a2_pos {
+                    break;
+                }
+                for (from, to) in [(a1_pos, a2_pos), (a2_pos, a1_pos)] {
+                    let antinodes = get_resonant_antinodes(*from, *to, board_size);
+                    budget.spend(antinodes.len() as u64)?;
+                    occupied_pos.extend(antinodes);
+                }
+            }
+        }
+    }
+    Ok(occupied_pos.len())
+}
+
+/// A `size` by `size` map with antennas of up to four frequencies on about
+/// one cell in ten.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const FREQUENCIES: &[char] = &['0', '7', 'a', 'q', 'A', 'Z'];
+    let size = size.max(2);
+    let frequencies: Vec<char> = (0..rng.range(1, 4))
+        .map(|_| *rng.pick(FREQUENCIES))
+        .collect();
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.chance(0.1) {
+                    true => *rng.pick(&frequencies),
+                    false => '.',
+                })
+                .collect()
+        })
+        .collect();
+    rows[0][0] = '.';
+    let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+    rows.join("\n")
+}
+
+const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+impl Solver for Solution {
+    type Parsed = (AntennaMap, Pos);
+    const TITLE: &'static str = "Resonant Collinearity";
+    const EXPLAIN_PARTS: &'static [i32] = &[1, 2];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: EXAMPLE,
+            answers: &[(1, "14"), (2, "34")],
+        },
+        Example {
+            name: "basic",
+            input: "##..",
+            answers: &[(2, "4")],
+        },
+    ];
+    const STRUCTURE: Structure = Structure::Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        parse(input)
+    }
+
+    /// Any text parses as a map, so require a rectangle of '.' and antennas.
+    fn looks_like(input: &str) -> bool {
+        let width = input.lines().next().map_or(0, str::len);
+        input.contains('.')
+            && input.lines().all(|line| {
+                line.len() == width && line.chars().all(|c| c == '.' || c.is_ascii_alphanumeric())
+            })
+    }
+
+    fn part1(parsed: &Self::Parsed, budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part1(parsed, budget)?.into())
+    }
+
+    fn part2(parsed: &Self::Parsed, budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(part2(parsed, budget)?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn explain(parsed: &Self::Parsed, part: i32) -> Result<String, AoCError> {
+        match part {
+            1 => Ok(explain_part1(parsed)),
+            2 => Ok(explain_part2(parsed)),
+            _ => Err(AoCError::new(ErrorKind::Other(format!(
+                "part {part} has no explanation"
+            )))),
+        }
+    }
+
+    fn stats((antenna_map, board_size): &Self::Parsed) -> Vec<(&'static str, usize)> {
+        let antennas = antenna_map.values().map(Vec::len).sum();
+        vec![
+            ("rows", board_size.0),
+            ("columns", board_size.1),
+            ("frequencies", antenna_map.len()),
+            ("antennas", antennas),
+        ]
+    }
+
+    fn visualize((antenna_map, board_size): &Self::Parsed) -> Option<String> {
+        Some(visualize(antenna_map, *board_size))
+    }
+}
@@ -0,0 +1,399 @@
+use crate::answer::Answer;
+use crate::aoc_solver::{Example, Solver};
+use crate::budget::Budget;
+use crate::error::AoCError;
+use crate::rng::Rng;
+use crate::shrink::Structure;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub struct Solution;
+
+fn parse(input: &str) -> Result<Vec<u32>, AoCError> {
+    let offset = input.len() - input.trim_start().len();
+    input
+        .trim()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| AoCError::unexpected_char(input, offset + i).expected("digit"))
+        })
+        .collect()
+}
+
+/// A run of consecutive blocks on the disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    start: usize,
+    size: usize,
+}
+
+impl Segment {
+    fn end(&self) -> usize {
+        self.start + self.size
+    }
+}
+
+/// The disk as file pieces and free runs, both ordered by position.
+///
+/// A file is a single piece until block-wise compaction splits it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Disk {
+    files: Vec<(u32, Segment)>,
+    free: Vec<Segment>,
+}
+
+fn part1_setup(disk_map: &[u32]) -> Disk {
+    let mut disk = Disk {
+        files: Vec::with_capacity(disk_map.len() / 2 + 1),
+        free: Vec::with_capacity(disk_map.len() / 2),
+    };
+    let mut position = 0;
+    for (i, size) in disk_map.iter().enumerate() {
+        let segment = Segment {
+            start: position,
+            size: *size as usize,
+        };
+        position += segment.size;
+        if i % 2 == 0 {
+            disk.files.push(((i / 2) as u32, segment));
+        } else if segment.size > 0 {
+            disk.free.push(segment);
+        }
+    }
+    disk
+}
+
+/// Moves blocks one at a time from the end of the disk to the leftmost free
+/// block, splitting files where needed.
+fn part1_compress(disk: Disk) -> Disk {
+    let mut pieces = disk.files;
+    let mut moved: Vec<(u32, Segment)> = Vec::new();
+    let mut free = disk.free.into_iter();
+    let mut current_free = free.next();
+    while let Some((id, piece)) = pieces.pop() {
+        let gap = match current_free {
+            Some(gap) if gap.start < piece.start => gap,
+            _ => {
+                pieces.push((id, piece));
+                break;
+            }
+        };
+        let size = gap.size.min(piece.size);
+        moved.push((
+            id,
+            Segment {
+                start: gap.start,
+                size,
+            },
+        ));
+        if piece.size > size {
+            pieces.push((
+                id,
+                Segment {
+                    start: piece.start,
+                    size: piece.size - size,
+                },
+            ));
+        }
+        current_free = if gap.size > size {
+            Some(Segment {
+                start: gap.start + size,
+                size: gap.size - size,
+            })
+        } else {
+            free.next()
+        };
+    }
+    let end = pieces.last().map_or(0, |(_, piece)| piece.end());
+    pieces.extend(moved);
+    pieces.sort_by_key(|(_, piece)| piece.start);
+    let mut compressed = Disk {
+        files: pieces,
+        free: Vec::new(),
+    };
+    compressed.recompute_free(end);
+    compressed
+}
+
+/// Moves every file, highest id first, to the leftmost free run it fits in.
+///
+/// Free runs are indexed by size, each size keeping a min-heap of start
+/// positions, so finding the leftmost fitting run is a look at the top of
+/// at most ten heaps. Space freed by a move is never reused: it lies right
+/// of every file that is still to be moved.
+fn part2_compress(disk: Disk) -> Disk {
+    let max_size = disk.free.iter().map(|f| f.size).max().unwrap_or(0);
+    let mut free_by_size: Vec<BinaryHeap<Reverse<usize>>> = vec![BinaryHeap::new(); max_size + 1];
+    for gap in disk.free.iter() {
+        free_by_size[gap.size].push(Reverse(gap.start));
+    }
+    let mut files = disk.files;
+    for (_, file) in files.iter_mut().rev() {
+        if file.size == 0 || file.size > max_size {
+            continue;
+        }
+        let best = (file.size..=max_size)
+            .filter_map(|size| {
+                free_by_size[size]
+                    .peek()
+                    .map(|Reverse(start)| (*start, size))
+            })
+            .min();
+        let (gap_start, gap_size) = match best {
+            Some((start, size)) if start < file.start => (start, size),
+            _ => continue,
+        };
+        free_by_size[gap_size].pop();
+        if gap_size > file.size {
+            free_by_size[gap_size - file.size].push(Reverse(gap_start + file.size));
+        }
+        file.start = gap_start;
+    }
+    let end = files.iter().map(|(_, f)| f.end()).max().unwrap_or(0);
+    files.sort_by_key(|(_, file)| file.start);
+    let mut compressed = Disk {
+        files,
+        free: Vec::new(),
+    };
+    compressed.recompute_free(end);
+    compressed
+}
+
+impl Disk {
+    /// Rebuilds the free runs from the file pieces, up to `end`.
+    fn recompute_free(&mut self, end: usize) {
+        let mut position = 0;
+        self.free.clear();
+        for (_, piece) in self.files.iter() {
+            if piece.start > position {
+                self.free.push(Segment {
+                    start: position,
+                    size: piece.start - position,
+                });
+            }
+            position = position.max(piece.end());
+        }
+        if end > position {
+            self.free.push(Segment {
+                start: position,
+                size: end - position,
+            });
+        }
+    }
+}
+
+fn part1_calculate_score(disk: &Disk) -> i128 {
+    disk.files
+        .iter()
+        .map(|(id, piece)| {
+            // Sum of the positions start..end, times the file id.
+            let positions =
+                (2 * piece.start as i128 + piece.size as i128 - 1) * piece.size as i128 / 2;
+            *id as i128 * positions
+        })
+        .sum()
+}
+
+/// The disk as one entry per block, holding the id of the file using it.
+fn reference_blocks(disk_map: &[u32], budget: &Budget) -> Result<Vec<Option<u32>>, AoCError> {
+    let mut blocks = Vec::new();
+    for (i, size) in disk_map.iter().enumerate() {
+        budget.spend(*size as u64)?;
+        let id = match i % 2 {
+            0 => Some((i / 2) as u32),
+            _ => None,
+        };
+        blocks.extend(std::iter::repeat_n(id, *size as usize));
+    }
+    Ok(blocks)
+}
+
+/// Moves the last file block into the first free block until no free
+/// block is left of a file block.
+fn reference_compact_blocks(blocks: &mut [Option<u32>], budget: &Budget) -> Result<(), AoCError> {
+    let (mut free, mut last) = (0, blocks.len());
+    loop {
+        budget.step()?;
+        while free < blocks.len() && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > 0 && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if last == 0 || free >= last - 1 {
+            return Ok(());
+        }
+        blocks.swap(free, last - 1);
+    }
+}
+
+/// Moves every file, highest id first, to the leftmost run of free blocks
+/// it fits in, searching block by block.
+fn reference_compact_files(blocks: &mut [Option<u32>], budget: &Budget) -> Result<(), AoCError> {
+    let max_id = blocks.iter().flatten().max().copied();
+    for id in (0..=max_id.unwrap_or(0)).rev() {
+        let start = match blocks.iter().position(|b| *b == Some(id)) {
+            Some(start) => start,
+            None => continue,
+        };
+        let size = blocks[start..]
+            .iter()
+            .take_while(|b| **b == Some(id))
+            .count();
+        let mut run = 0;
+        for position in 0..start {
+            budget.step()?;
+            run = match blocks[position] {
+                None => run + 1,
+                Some(_) => 0,
+            };
+            if run == size {
+                let target = position + 1 - size;
+                for offset in 0..size {
+                    blocks.swap(target + offset, start + offset);
+                }
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn reference_checksum(blocks: &[Option<u32>]) -> i128 {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(position, id)| id.map(|id| position as i128 * id as i128))
+        .sum()
+}
+
+fn reference(disk_map: &[u32], part: i32, budget: &Budget) -> Result<i128, AoCError> {
+    let mut blocks = reference_blocks(disk_map, budget)?;
+    match part {
+        1 => reference_compact_blocks(&mut blocks, budget)?,
+        _ => reference_compact_files(&mut blocks, budget)?,
+    }
+    Ok(reference_checksum(&blocks))
+}
+
+/// A disk map of `size` files of one to nine blocks.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1) * 2 - 1)
+        .map(|i| match i % 2 {
+            0 => rng.range(1, 9),
+            _ => rng.range(0, 9),
+        })
+        .map(|blocks| blocks.to_string())
+        .collect()
+}
+
+const EXAMPLE: &str = "2333133121414131402";
+
+impl Solver for Solution {
+    type Parsed = Vec<u32>;
+    const TITLE: &'static str = "Disk Fragmenter";
+    const REFERENCE_PARTS: &'static [i32] = &[1, 2];
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[(1, "1928"), (2, "2858")],
+    }];
+    const STRUCTURE: Structure = Structure::Chars;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AoCError> {
+        parse(input)
+    }
+
+    fn part1(disk_map: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        let disk = part1_setup(disk_map.as_slice());
+        let disk = part1_compress(disk);
+        let score = part1_calculate_score(&disk);
+        Ok(score.into())
+    }
+
+    fn part2(disk_map: &Self::Parsed, _budget: &Budget) -> Result<Answer, AoCError> {
+        let disk = part1_setup(disk_map.as_slice());
+        let disk = part2_compress(disk);
+        let score = part1_calculate_score(&disk);
+        Ok(score.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn reference(disk_map: &Self::Parsed, part: i32, budget: &Budget) -> Result<Answer, AoCError> {
+        Ok(reference(disk_map, part, budget)?.into())
+    }
+
+    fn stats(disk_map: &Self::Parsed) -> Vec<(&'static str, usize)> {
+        let blocks = disk_map.iter().map(|&len| len as usize).sum();
+        vec![("files", disk_map.len().div_ceil(2)), ("blocks", blocks)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{Disk, Solution};
+    use crate::answer::Answer;
+    use crate::aoc_solver::DynSolver;
+
+    fn render(disk: &Disk) -> String {
+        let len = disk
+            .files
+            .iter()
+            .map(|(_, f)| f.end())
+            .chain(disk.free.iter().map(|f| f.end()))
+            .max()
+            .unwrap_or(0);
+        let mut blocks = vec!['.'; len];
+        for (id, piece) in disk.files.iter() {
+            for block in blocks[piece.start..piece.end()].iter_mut() {
+                *block = char::from_digit(id % 10, 10).unwrap();
+            }
+        }
+        blocks.into_iter().collect()
+    }
+
+    #[test]
+    fn test_part1_setup() {
+        let disk = super::part1_setup(&[1, 2, 3, 4, 5]);
+        assert_eq!(render(&disk), "0..111....22222");
+        assert_eq!(disk.free.len(), 2);
+    }
+
+    #[test]
+    fn test_part1_compress() {
+        let disk_map = super::parse("12345").unwrap();
+        let disk = super::part1_setup(disk_map.as_slice());
+        let disk = super::part1_compress(disk);
+        assert_eq!(render(&disk), "022111222");
+        assert_eq!(disk.files.len(), 4);
+    }
+
+    #[test]
+    fn test_part2_compress() {
+        let disk_map = super::parse("2333133121414131402").unwrap();
+        let disk = super::part1_setup(disk_map.as_slice());
+        let disk = super::part2_compress(disk);
+        assert_eq!(render(&disk), "00992111777.44.333....5555.6666.....8888");
+    }
+
+    #[test]
+    fn test_part2_compress_unmovable() {
+        let disk_map = super::parse("12345").unwrap();
+        let disk = super::part1_setup(disk_map.as_slice());
+        let compressed = super::part2_compress(disk.clone());
+        assert_eq!(compressed, disk);
+        assert_eq!(Solution.solve("12345", 2).unwrap(), Answer::Integer(132));
+    }
+
+    #[test]
+    fn test_large_disk() {
+        let input: String = (0..200_000).map(|i| ["9", "1", "5", "3"][i % 4]).collect();
+        assert!(Solution.solve(&input, 1).is_ok());
+        assert!(Solution.solve(&input, 2).is_ok());
+    }
+}
@@ -0,0 +1,164 @@
+//! Differential testing of solutions against their reference solvers.
+//!
+//! A day's reference parts, see [`Solver::reference`], are slow but
+//! straightforward. [`first_disagreement`] runs them next to the real parts
+//! on many inputs and stops at the first input the two answer differently,
+//! so an optimized part can be checked against the obvious one.
+//!
+//! [`Solver::reference`]: crate::aoc_solver::Solver::reference
+
+use crate::answer::Answer;
+use crate::aoc_solver::DynSolver;
+use crate::budget::Budget;
+use crate::error::{AoCError, ErrorKind};
+use std::fmt;
+
+/// An input on which a part and its reference answer differently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: i32,
+    pub input: String,
+    /// Answer of the part, or its error.
+    pub solution: Result<String, String>,
+    /// Answer of the reference, or its error.
+    pub reference: Result<String, String>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show = |result: &Result<String, String>| match result {
+            Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {}", e.lines().next().unwrap_or("")),
+        };
+        writeln!(f, "part {} disagrees with its reference", self.part)?;
+        writeln!(f, "solution:  {}", show(&self.solution))?;
+        writeln!(f, "reference: {}", show(&self.reference))?;
+        write!(f, "input:\n{}", self.input)
+    }
+}
+
+/// The displayed answer or error, `None` if the budget ran out first.
+fn outcome(result: Result<Answer, AoCError>) -> Option<Result<String, String>> {
+    match result {
+        Ok(answer) => Some(Ok(answer.to_string())),
+        Err(e)
+            if matches!(
+                e.kind,
+                ErrorKind::BudgetExceeded { .. } | ErrorKind::Cancelled
+            ) =>
+        {
+            None
+        }
+        Err(e) => Some(Err(e.to_string())),
+    }
+}
+
+/// Runs every reference part of `solver` and its real part on `input`,
+/// each with `max_steps` to spend.
+///
+/// Both sides agree when they give the same answer or both fail. Inputs
+/// that do not parse, and parts where either side runs out of steps, are
+/// not compared.
+pub fn compare(solver: &dyn DynSolver, input: &str, max_steps: u64) -> Option<Disagreement> {
+    let parsed = solver.parse(input).ok()?;
+    solver.reference_parts().into_iter().find_map(|part| {
+        let solution =
+            outcome(solver.solve_parsed(parsed.as_ref(), part, &Budget::max_steps(max_steps)))?;
+        let reference =
+            outcome(solver.reference_parsed(parsed.as_ref(), part, &Budget::max_steps(max_steps)))?;
+        let agree = match (&solution, &reference) {
+            (Ok(a), Ok(b)) => a == b,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        match agree {
+            true => None,
+            false => Some(Disagreement {
+                part,
+                input: input.to_string(),
+                solution,
+                reference,
+            }),
+        }
+    })
+}
+
+/// The first of `inputs` on which `solver` disagrees with its reference.
+pub fn first_disagreement(
+    solver: &dyn DynSolver,
+    inputs: impl IntoIterator<Item = String>,
+    max_steps: u64,
+) -> Option<Disagreement> {
+    inputs
+        .into_iter()
+        .find_map(|input| compare(solver, &input, max_steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, first_disagreement};
+    use crate::answer::Answer;
+    use crate::aoc_solver::Solver;
+    use crate::budget::Budget;
+    use crate::error::{AoCError, ErrorKind};
+    use crate::rng::Rng;
+
+    /// Sums numbers, with a reference that gets sums above 100 wrong.
+    struct Sum;
+
+    impl Solver for Sum {
+        type Parsed = Vec<i64>;
+        const TITLE: &'static str = "Sum";
+        const REFERENCE_PARTS: &'static [i32] = &[1];
+
+        fn parse(input: &str) -> Result<Vec<i64>, AoCError> {
+            input
+                .split_whitespace()
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| AoCError::new(ErrorKind::InvalidInput))
+                })
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<i64>, budget: &Budget) -> Result<Answer, AoCError> {
+            budget.spend(numbers.len() as u64)?;
+            Ok(numbers.iter().sum::<i64>().into())
+        }
+
+        fn part2(_numbers: &Vec<i64>, _budget: &Budget) -> Result<Answer, AoCError> {
+            Ok(0.into())
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            let numbers: Vec<String> = (0..size).map(|_| rng.range(0, 99).to_string()).collect();
+            numbers.join(" ")
+        }
+
+        fn reference(numbers: &Vec<i64>, _part: i32, _budget: &Budget) -> Result<Answer, AoCError> {
+            Ok(numbers.iter().sum::<i64>().min(100).into())
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare(&Sum, "1 2 3", 10), None);
+        assert_eq!(compare(&Sum, "1 x", 10), None);
+        assert_eq!(compare(&Sum, "100 1", 1), None);
+        let disagreement = compare(&Sum, "100 1", 10).unwrap();
+        assert_eq!(disagreement.part, 1);
+        assert_eq!(disagreement.solution, Ok("101".to_string()));
+        assert_eq!(disagreement.reference, Ok("100".to_string()));
+    }
+
+    #[test]
+    fn test_first_disagreement() {
+        let inputs = ["1", "50 50", "99 2", "200"].iter().map(|s| s.to_string());
+        let disagreement = first_disagreement(&Sum, inputs, 10).unwrap();
+        assert_eq!(disagreement.input, "99 2");
+        assert_eq!(
+            disagreement.to_string(),
+            "part 1 disagrees with its reference\nsolution:  101\nreference: 100\ninput:\n99 2"
+        );
+    }
+}
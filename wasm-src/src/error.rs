@@ -0,0 +1,201 @@
+use derive_more::derive::Display;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum ErrorKind {
+    #[display("unexpected character {_0:?}")]
+    UnexpectedCharacter(char),
+    #[display("unexpected end of input")]
+    UnexpectedEnd,
+    #[display("invalid input")]
+    InvalidInput,
+    #[display("row has {found} columns but the first row has {expected}")]
+    InconsistentRowLength { expected: usize, found: usize },
+    #[display("found no start position ('^')")]
+    NoStartPosition,
+    #[display("found multiple start positions ('^'), the first is at {}:{}", first.0, first.1)]
+    MultipleStartPositions { first: (usize, usize) },
+    #[display("the guard walks in a loop and never leaves the map")]
+    GuardNeverLeaves,
+    #[display("step budget of {limit} exceeded")]
+    BudgetExceeded { limit: u64 },
+    #[display("cancelled")]
+    Cancelled,
+    #[display("{_0}")]
+    Other(String),
+}
+
+/// Location of an error in the input.
+///
+/// `start`/`end` are byte offsets into the normalized input the day parsed,
+/// `line` and `column` are 1-based and `source_line` is the full line the
+/// span starts on. Normalizing only unifies newlines, drops a byte order
+/// mark and trailing whitespace, or swaps tabs for spaces, so `line` and
+/// `column`, which count characters, also point into the raw input, a byte
+/// order mark aside.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+}
+
+impl Span {
+    pub fn new(input: &str, start: usize, end: usize) -> Span {
+        let start = start.min(input.len());
+        let end = end.clamp(start, input.len());
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        Span {
+            start,
+            end,
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            source_line: input[line_start..line_end].to_string(),
+        }
+    }
+
+    /// Span of the character starting at byte `offset`.
+    pub fn char_at(input: &str, offset: usize) -> Span {
+        let len = input[offset.min(input.len())..]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+        Span::new(input, offset, offset + len)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AoCError {
+    pub kind: ErrorKind,
+    pub span: Option<Span>,
+    pub expected: Option<String>,
+}
+
+impl AoCError {
+    pub fn new(kind: ErrorKind) -> AoCError {
+        AoCError {
+            kind,
+            span: None,
+            expected: None,
+        }
+    }
+
+    pub fn at(mut self, span: Span) -> AoCError {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn expected(mut self, expected: impl Into<String>) -> AoCError {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Error for the unexpected character at byte `offset` of `input`.
+    pub fn unexpected_char(input: &str, offset: usize) -> AoCError {
+        let kind = match input.get(offset..).and_then(|rest| rest.chars().next()) {
+            Some(c) => ErrorKind::UnexpectedCharacter(c),
+            None => ErrorKind::UnexpectedEnd,
+        };
+        AoCError::new(kind).at(Span::char_at(input, offset))
+    }
+
+    /// Converts a nom error, locating it by the input left when it failed.
+    #[cfg(feature = "nom")]
+    pub fn from_nom(input: &str, e: nom::Err<nom::error::VerboseError<&str>>) -> AoCError {
+        let errors = match e {
+            nom::Err::Error(ve) | nom::Err::Failure(ve) => ve.errors,
+            nom::Err::Incomplete(_) => return AoCError::new(ErrorKind::UnexpectedEnd),
+        };
+        let (rest, first_kind) = match errors.first() {
+            Some(first) => first,
+            None => return AoCError::new(ErrorKind::InvalidInput),
+        };
+        let offset = input.len() - rest.len();
+        let mut error = if rest.is_empty() {
+            AoCError::new(ErrorKind::UnexpectedEnd).at(Span::new(input, offset, offset))
+        } else {
+            AoCError::unexpected_char(input, offset)
+        };
+        let context = errors.iter().find_map(|(_, kind)| match kind {
+            nom::error::VerboseErrorKind::Context(c) => Some(c.to_string()),
+            _ => None,
+        });
+        let expected = context.unwrap_or_else(|| match first_kind {
+            nom::error::VerboseErrorKind::Char(c) => format!("{c:?}"),
+            nom::error::VerboseErrorKind::Nom(nom::error::ErrorKind::Eof) => {
+                "end of input".to_string()
+            }
+            nom::error::VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+            nom::error::VerboseErrorKind::Context(c) => c.to_string(),
+        });
+        error.expected = Some(expected);
+        error
+    }
+}
+
+impl fmt::Display for AoCError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error: {}", self.kind)?;
+        if let Some(expected) = &self.expected {
+            write!(f, ", expected {expected}")?;
+        }
+        if let Some(span) = &self.span {
+            let line_no = span.line.to_string();
+            let pad = " ".repeat(line_no.len());
+            // The span's characters on its first line, at least one.
+            let from = span
+                .source_line
+                .char_indices()
+                .nth(span.column - 1)
+                .map_or(span.source_line.len(), |(i, _)| i);
+            let width = span.source_line[from..]
+                .char_indices()
+                .take_while(|&(i, _)| i < span.end - span.start)
+                .count()
+                .max(1);
+            let indent = " ".repeat(span.column - 1);
+            write!(f, "\n{pad}--> {}:{}", span.line, span.column)?;
+            write!(f, "\n{pad} |")?;
+            write!(f, "\n{line_no} | {}", span.source_line)?;
+            write!(f, "\n{pad} | {indent}{}", "^".repeat(width))?;
+        }
+        Ok(())
+    }
+}
+
+impl From<String> for AoCError {
+    fn from(message: String) -> AoCError {
+        AoCError::new(ErrorKind::Other(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AoCError, ErrorKind, Span};
+
+    #[test]
+    fn test_span() {
+        let span = Span::new("12\n3a4\n", 4, 5);
+        assert_eq!(span.line, 2);
+        assert_eq!(span.column, 2);
+        assert_eq!(span.source_line, "3a4");
+    }
+
+    #[test]
+    fn test_render() {
+        let error = AoCError::unexpected_char("12\n3a4\n", 4).expected("digit");
+        assert_eq!(error.kind, ErrorKind::UnexpectedCharacter('a'));
+        assert_eq!(
+            error.to_string(),
+            "error: unexpected character 'a', expected digit\n --> 2:2\n  |\n2 | 3a4\n  |  ^"
+        );
+        let error = AoCError::new(ErrorKind::InvalidInput).at(Span::new("aé€x", 1, 6));
+        assert_eq!(
+            error.to_string(),
+            "error: invalid input\n --> 1:2\n  |\n1 | aé€x\n  |  ^^"
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Regression checks of real puzzle inputs against their known answers.
+//!
+//! Inputs are personal, so they are kept outside the repository, in a data
+//! directory laid out as
+//!
+//! ```text
+//! day_09/main.txt          an input of the default year
+//! day_09/main.answers      its answers, one `part: answer` per line
+//! y2023/day_01/alice.txt   inputs of other years
+//! answers.key              the key hashed answers are recorded with
+//! ```
+//!
+//! Answers can also come from an [`AnswerStore`], which holds them hashed
+//! with the [`Key`] in the data directory so they can be committed. Its
+//! entries are looked up for the user named in `AOC_USER`, and checked
+//! against every input of their day, so keep one input per day or give the
+//! others an answers file.
+//!
+//! [`run`] solves every input that has answers and reports each registered
+//! day and part, skipping those without an input or an answer.
+
+use crate::aoc_solver::{self, Puzzle, DEFAULT_YEAR};
+use crate::verify::{AnswerStore, Entry, Key};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the data directory.
+pub const DATA_DIR_VAR: &str = "AOC_DATA";
+
+/// Environment variable naming whose answers the data directory holds.
+pub const USER_VAR: &str = "AOC_USER";
+
+/// File in the data directory holding the user's [`Key`].
+pub const KEY_FILE: &str = "answers.key";
+
+/// An input found in the data directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldenInput {
+    pub year: i32,
+    pub day: i32,
+    /// File stem of the input, e.g. `main`.
+    pub name: String,
+    pub path: PathBuf,
+    /// Expected answer, as displayed, per part.
+    pub answers: Vec<(i32, String)>,
+}
+
+impl GoldenInput {
+    fn answer(&self, part: i32) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.as_str())
+    }
+}
+
+/// Number following `prefix` in a file or directory name.
+fn numbered(path: &Path, prefix: &str) -> Option<i32> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .parse()
+        .ok()
+}
+
+/// Parses an answers file, skipping blank lines and `#` comments.
+fn parse_answers(text: &str) -> Result<Vec<(i32, String)>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (part, answer) = line
+                .split_once(':')
+                .ok_or_else(|| format!("expected `part: answer`, found {line:?}"))?;
+            let part = part
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid part {:?}", part.trim()))?;
+            Ok((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+fn read_answers(path: &Path) -> io::Result<Vec<(i32, String)>> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse_answers(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn discover_day(dir: &Path, year: i32, day: i32) -> io::Result<Vec<GoldenInput>> {
+    let mut inputs = Vec::new();
+    for path in sorted_entries(dir)? {
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let name = match path.file_stem().and_then(|s| s.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        inputs.push(GoldenInput {
+            year,
+            day,
+            answers: read_answers(&path.with_extension("answers"))?,
+            name,
+            path,
+        });
+    }
+    Ok(inputs)
+}
+
+/// Every input in the data directory, ordered by year, day and name.
+///
+/// A missing directory holds no inputs.
+pub fn discover(dir: &Path) -> io::Result<Vec<GoldenInput>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut inputs = Vec::new();
+    for path in sorted_entries(dir)? {
+        if let Some(day) = numbered(&path, "day_") {
+            inputs.extend(discover_day(&path, DEFAULT_YEAR, day)?);
+        } else if let Some(year) = numbered(&path, "y").filter(|_| path.is_dir()) {
+            for day_path in sorted_entries(&path)? {
+                if let Some(day) = numbered(&day_path, "day_") {
+                    inputs.extend(discover_day(&day_path, year, day)?);
+                }
+            }
+        }
+    }
+    inputs.sort_by(|a, b| (a.year, a.day, &a.name).cmp(&(b.year, b.day, &b.name)));
+    Ok(inputs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, got: String },
+    Skipped(&'static str),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Fail { expected, got } => {
+                let got = got.lines().next().unwrap_or("");
+                write!(f, "FAIL expected {expected}, got {got}")
+            }
+            Outcome::Skipped(reason) => write!(f, "skipped ({reason})"),
+        }
+    }
+}
+
+/// The outcome of one part on one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub year: i32,
+    pub day: i32,
+    /// Name of the input, `-` when the day has none.
+    pub input: String,
+    pub part: i32,
+    pub outcome: Outcome,
+}
+
+/// What an answer is checked against.
+enum Expected<'a> {
+    Plain(&'a str),
+    Hashed(&'a Entry, &'a Key),
+}
+
+impl Expected<'_> {
+    fn matches(&self, got: &str) -> bool {
+        match self {
+            Expected::Plain(answer) => *answer == got,
+            Expected::Hashed(entry, key) => entry.matches(key, got),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Expected::Plain(answer) => answer.to_string(),
+            Expected::Hashed(..) => "the recorded answer".to_string(),
+        }
+    }
+}
+
+fn check_input(
+    input: &GoldenInput,
+    parts: &[i32],
+    store: &AnswerStore,
+    user: Option<&str>,
+    key: Option<&Key>,
+) -> io::Result<Vec<Check>> {
+    let text = fs::read_to_string(&input.path)?;
+    let puzzle = Puzzle::new(&text, input.year, input.day);
+    let checks = parts
+        .iter()
+        .map(|&part| {
+            let recorded = store.recorded(input.year, input.day, part);
+            let entry = user.and_then(|user| store.get(input.year, input.day, part, user));
+            let expected = match (input.answer(part), entry, key) {
+                (Some(answer), _, _) => Ok(Expected::Plain(answer)),
+                (None, Some(entry), Some(key)) => Ok(Expected::Hashed(entry, key)),
+                (None, Some(_), None) => Err("no key"),
+                (None, None, _) if recorded && user.is_none() => Err("no user"),
+                (None, None, _) => Err("no answer"),
+            };
+            let outcome = match expected {
+                Err(reason) => Outcome::Skipped(reason),
+                Ok(expected) => {
+                    let got = match &puzzle {
+                        Ok(puzzle) => puzzle.solve(part).map(|a| a.to_string()),
+                        Err(e) => Err(e.clone()),
+                    };
+                    match got {
+                        Ok(got) if expected.matches(&got) => Outcome::Pass,
+                        Ok(got) | Err(got) => Outcome::Fail {
+                            expected: expected.describe(),
+                            got,
+                        },
+                    }
+                }
+            };
+            Check {
+                year: input.year,
+                day: input.day,
+                input: input.name.clone(),
+                part,
+                outcome,
+            }
+        })
+        .collect();
+    Ok(checks)
+}
+
+/// Checks every registered day and part against the inputs in `dir`, with
+/// answers from next to the inputs or else from `user`'s entries in `store`,
+/// verified with the key in `dir`.
+///
+/// Inputs of days that are not registered, e.g. because their feature is
+/// off, are reported as skipped.
+pub fn run(dir: &Path, store: &AnswerStore, user: Option<&str>) -> io::Result<Vec<Check>> {
+    let inputs = discover(dir)?;
+    let key = Key::load(&dir.join(KEY_FILE))?;
+    let mut checks = Vec::new();
+    for info in aoc_solver::puzzles() {
+        let day_inputs: Vec<&GoldenInput> = inputs
+            .iter()
+            .filter(|i| (i.year, i.day) == (info.year, info.day))
+            .collect();
+        if day_inputs.is_empty() {
+            checks.extend(info.parts.iter().map(|&part| Check {
+                year: info.year,
+                day: info.day,
+                input: "-".to_string(),
+                part,
+                outcome: Outcome::Skipped("no input"),
+            }));
+        }
+        for input in day_inputs {
+            checks.extend(check_input(input, &info.parts, store, user, key.as_ref())?);
+        }
+    }
+    for input in inputs
+        .iter()
+        .filter(|i| aoc_solver::solver(i.year, i.day).is_none())
+    {
+        checks.extend(input.answers.iter().map(|&(part, _)| Check {
+            year: input.year,
+            day: input.day,
+            input: input.name.clone(),
+            part,
+            outcome: Outcome::Skipped("not registered"),
+        }));
+    }
+    Ok(checks)
+}
+
+pub fn passed(checks: &[Check]) -> bool {
+    checks
+        .iter()
+        .all(|c| !matches!(c.outcome, Outcome::Fail { .. }))
+}
+
+/// Renders the checks as a table followed by a summary line.
+pub fn table(checks: &[Check]) -> String {
+    let width = checks
+        .iter()
+        .map(|c| c.input.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or(0);
+    let mut out = format!("year  day  {:width$}  part  result\n", "input");
+    for c in checks {
+        out.push_str(&format!(
+            "{:<4}  {:>3}  {:width$}  {:>4}  {}\n",
+            c.year, c.day, c.input, c.part, c.outcome
+        ));
+    }
+    let count = |f: fn(&Outcome) -> bool| checks.iter().filter(|c| f(&c.outcome)).count();
+    out.push_str(&format!(
+        "{} passed, {} failed, {} skipped\n",
+        count(|o| *o == Outcome::Pass),
+        count(|o| matches!(o, Outcome::Fail { .. })),
+        count(|o| matches!(o, Outcome::Skipped(_)))
+    ));
+    out
+}
+
+#[cfg(all(test, feature = "y2024_day_01"))]
+mod tests {
+    use super::{discover, parse_answers, passed, run, table, Outcome, KEY_FILE};
+    use crate::answer::Answer;
+    use crate::verify::{AnswerStore, Entry, Key};
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// A fresh directory holding `files`, given as `(path, contents)`.
+    fn data_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-golden-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (path, contents) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("# day 1\n1: 11\n\n2:31 \n"),
+            Ok(vec![(1, "11".to_string()), (2, "31".to_string())])
+        );
+        assert!(parse_answers("11\n").is_err());
+        assert!(parse_answers("one: 11\n").is_err());
+    }
+
+    #[test]
+    fn test_discover() {
+        let dir = data_dir(
+            "discover",
+            &[
+                ("day_01/b.txt", "3   4\n"),
+                ("day_01/a.txt", "3   4\n"),
+                ("day_01/a.answers", "1: 1\n"),
+                ("day_01/notes.md", ""),
+                ("y1999/day_02/main.txt", ""),
+            ],
+        );
+        let found: Vec<(i32, i32, String, usize)> = discover(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|i| (i.year, i.day, i.name, i.answers.len()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1999, 2, "main".to_string(), 0),
+                (2024, 1, "a".to_string(), 1),
+                (2024, 1, "b".to_string(), 0),
+            ]
+        );
+        assert!(discover(&dir.join("missing")).unwrap().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = data_dir(
+            "run",
+            &[
+                ("day_01/main.txt", "3   4\n4   3\n"),
+                ("day_01/main.answers", "1: 0\n2: 8\n"),
+                ("day_01/other.txt", "3   4\n4   3\n"),
+                ("y1999/day_01/main.txt", ""),
+                ("y1999/day_01/main.answers", "1: 1\n"),
+            ],
+        );
+        let key = Key::load_or_create(&dir.join(KEY_FILE)).unwrap();
+        let mut store = AnswerStore::default();
+        store.record(Entry::new(2024, 1, 1, "alice", &key, &Answer::Integer(0)));
+        store.record(Entry::new(2024, 1, 2, "alice", &key, &Answer::Integer(8)));
+        let checks = run(&dir, &store, Some("alice")).unwrap();
+        let outcome = |year, day, input: &str, part| {
+            checks
+                .iter()
+                .find(|c| (c.year, c.day, c.input.as_str(), c.part) == (year, day, input, part))
+                .map(|c| c.outcome.clone())
+        };
+        assert_eq!(outcome(2024, 1, "main", 1), Some(Outcome::Pass));
+        assert_eq!(
+            outcome(2024, 1, "main", 2),
+            Some(Outcome::Fail {
+                expected: "8".to_string(),
+                got: "7".to_string()
+            })
+        );
+        assert_eq!(outcome(2024, 1, "other", 1), Some(Outcome::Pass));
+        assert_eq!(
+            outcome(2024, 1, "other", 2),
+            Some(Outcome::Fail {
+                expected: "the recorded answer".to_string(),
+                got: "7".to_string()
+            })
+        );
+        assert_eq!(
+            outcome(1999, 1, "main", 1),
+            Some(Outcome::Skipped("not registered"))
+        );
+        assert!(!passed(&checks));
+        let table = table(&checks);
+        assert!(table.contains("2024    1  main      2  FAIL expected 8, got 7\n"));
+        assert!(table.contains("\n2 passed, 2 failed, "));
+
+        let skipped = |user, remove_key| {
+            if remove_key {
+                fs::remove_file(dir.join(KEY_FILE)).unwrap();
+            }
+            run(&dir, &store, user)
+                .unwrap()
+                .into_iter()
+                .find(|c| (c.input.as_str(), c.part) == ("other", 1))
+                .map(|c| c.outcome)
+        };
+        assert_eq!(skipped(None, false), Some(Outcome::Skipped("no user")));
+        assert_eq!(
+            skipped(Some("bob"), false),
+            Some(Outcome::Skipped("no answer"))
+        );
+        assert_eq!(
+            skipped(Some("alice"), true),
+            Some(Outcome::Skipped("no key"))
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
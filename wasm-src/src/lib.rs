@@ -1,6 +1,21 @@
-mod aoc_solver;
+pub mod answer;
+pub mod aoc_solver;
+pub mod budget;
 mod days;
+pub mod differential;
+pub mod error;
+#[cfg(feature = "harness")]
+pub mod golden;
+pub mod log;
+pub mod normalize;
+pub mod progress;
+pub mod rng;
+pub mod shrink;
+#[cfg(feature = "harness")]
+pub mod verify;
 
+use budget::Budget;
+use progress::Progress;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -15,15 +30,326 @@ pub fn greet() {
 
 #[wasm_bindgen(js_name=test_fn)]
 pub fn test_ok() -> Result<String, String> {
-    return Ok("Was successfull".to_string());
+    Ok("Was successfull".to_string())
 }
 
 #[wasm_bindgen(js_name=test_err)]
 pub fn test_err() -> Result<String, String> {
-    return Err("Was error".to_string());
+    Err("Was error".to_string())
 }
 
+/// Year to use when JS passes `undefined` or `null`.
+fn year_or_default(year: Option<i32>) -> i32 {
+    year.unwrap_or(aoc_solver::DEFAULT_YEAR)
+}
+
+/// Step budget for `max_steps`, unlimited when JS passes `undefined`.
+fn budget(max_steps: Option<f64>) -> Budget {
+    match max_steps {
+        Some(steps) => Budget::max_steps(steps as u64),
+        None => Budget::unlimited(),
+    }
+}
+
+/// Solves one part. `year` defaults to 2024 when `undefined`, and the
+/// solve fails once it has spent `max_steps`, if given.
 #[wasm_bindgen(js_name=solve)]
-pub fn solve(input: String, day: i32, part: i32) -> Result<String, String> {
-    return aoc_solver::solve(&input, day, part);
+pub fn solve(
+    input: String,
+    year: Option<i32>,
+    day: i32,
+    part: i32,
+    max_steps: Option<f64>,
+) -> Result<JsValue, String> {
+    aoc_solver::solve_with_budget(&input, year_or_default(year), day, part, &budget(max_steps))
+        .map(|answer| answer.to_js())
+}
+
+/// Forwards progress reports to a JS callback `(fraction, message)`.
+///
+/// Exceptions thrown by the callback are ignored.
+struct JsProgress(js_sys::Function);
+
+impl Progress for JsProgress {
+    fn report(&self, fraction: f64, message: Option<&str>) {
+        let message = message.map_or(JsValue::UNDEFINED, JsValue::from_str);
+        let _ = self.0.call2(&JsValue::NULL, &fraction.into(), &message);
+    }
+}
+
+/// Budget that also reports progress to `on_progress`, if given.
+fn budget_with_progress(max_steps: Option<f64>, on_progress: Option<js_sys::Function>) -> Budget {
+    match on_progress {
+        Some(callback) => budget(max_steps).with_progress(JsProgress(callback)),
+        None => budget(max_steps),
+    }
+}
+
+/// Like `solve`, calling `on_progress(fraction, message)` while slow
+/// parts run.
+///
+/// The solve runs synchronously, so to repaint while it runs call this
+/// from a worker and post the reports to the page.
+#[wasm_bindgen(js_name=solve_with_progress)]
+pub fn solve_with_progress(
+    input: String,
+    year: Option<i32>,
+    day: i32,
+    part: i32,
+    on_progress: Option<js_sys::Function>,
+    max_steps: Option<f64>,
+) -> Result<JsValue, String> {
+    let budget = budget_with_progress(max_steps, on_progress);
+    aoc_solver::solve_with_budget(&input, year_or_default(year), day, part, &budget)
+        .map(|answer| answer.to_js())
+}
+
+/// Converts per-part results to `[{ part, answer, error }]`, where exactly
+/// one of `answer` and `error` is set.
+fn day_result_to_js(parts: aoc_solver::DayResult) -> JsValue {
+    let results = js_sys::Array::new();
+    for (part, result) in parts {
+        let (answer, error) = match result {
+            Ok(answer) => (answer.to_js(), JsValue::NULL),
+            Err(e) => (JsValue::NULL, JsValue::from_str(&e)),
+        };
+        let object = js_sys::Object::new();
+        for (key, value) in [("part", part.into()), ("answer", answer), ("error", error)] {
+            js_sys::Reflect::set(&object, &key.into(), &value).unwrap();
+        }
+        results.push(&object);
+    }
+    results.into()
+}
+
+/// Solves every part of a day, parsing the input once.
+///
+/// A parse error is reported on every part.
+#[wasm_bindgen(js_name=solve_day)]
+pub fn solve_day(
+    input: String,
+    year: Option<i32>,
+    day: i32,
+    max_steps: Option<f64>,
+) -> Result<JsValue, String> {
+    let year = year_or_default(year);
+    let parts = match aoc_solver::solve_day_with_budget(&input, year, day, &budget(max_steps)) {
+        Ok(parts) => parts,
+        Err(e) => match aoc_solver::solver(year, day) {
+            Some(s) => s.parts().into_iter().map(|p| (p, Err(e.clone()))).collect(),
+            None => return Err(e),
+        },
+    };
+    Ok(day_result_to_js(parts))
+}
+
+/// A day's input parsed once and kept in wasm memory.
+///
+/// Parse errors are thrown from the constructor. Call `free()` when done.
+#[wasm_bindgen]
+pub struct ParsedInput {
+    puzzle: aoc_solver::Puzzle,
+}
+
+#[wasm_bindgen]
+impl ParsedInput {
+    #[wasm_bindgen(constructor)]
+    pub fn new(input: &str, year: Option<i32>, day: i32) -> Result<ParsedInput, String> {
+        Ok(ParsedInput {
+            puzzle: aoc_solver::Puzzle::new(input, year_or_default(year), day)?,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn year(&self) -> i32 {
+        self.puzzle.year()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> i32 {
+        self.puzzle.day()
+    }
+
+    pub fn parts(&self) -> Vec<i32> {
+        self.puzzle.parts()
+    }
+
+    pub fn explain_parts(&self) -> Vec<i32> {
+        self.puzzle.explain_parts()
+    }
+
+    pub fn solve(&self, part: i32, max_steps: Option<f64>) -> Result<JsValue, String> {
+        self.puzzle
+            .solve_with_budget(part, &budget(max_steps))
+            .map(|answer| answer.to_js())
+    }
+
+    /// Like `solve`, calling `on_progress(fraction, message)` while slow
+    /// parts run.
+    pub fn solve_with_progress(
+        &self,
+        part: i32,
+        on_progress: Option<js_sys::Function>,
+        max_steps: Option<f64>,
+    ) -> Result<JsValue, String> {
+        self.puzzle
+            .solve_with_budget(part, &budget_with_progress(max_steps, on_progress))
+            .map(|answer| answer.to_js())
+    }
+
+    /// Solves every part, with `max_steps` shared between them.
+    pub fn solve_all(&self, max_steps: Option<f64>) -> JsValue {
+        day_result_to_js(self.puzzle.solve_all_with_budget(&budget(max_steps)))
+    }
+
+    pub fn explain(&self, part: i32) -> Result<String, String> {
+        self.puzzle.explain(part)
+    }
+
+    /// `{ bytes, lines, ... }` of the input, along with the day's own counts
+    /// of what it parsed, e.g. `rows` and `obstacles`.
+    pub fn stats(&self) -> JsValue {
+        let (bytes, lines) = self.puzzle.input_size();
+        let object = js_sys::Object::new();
+        let mut stats = vec![("bytes", bytes), ("lines", lines)];
+        stats.extend(self.puzzle.stats());
+        for (key, value) in stats {
+            js_sys::Reflect::set(&object, &key.into(), &(value as f64).into()).unwrap();
+        }
+        object.into()
+    }
+
+    /// A drawing of the parsed input, `undefined` if the day has none.
+    pub fn visualize(&self) -> Option<String> {
+        self.puzzle.visualize()
+    }
+}
+
+/// Calls `f` with solver logging enabled as `filter` says, e.g.
+/// `with_logging("day_03=debug", () => solve(input, 2024, 3, 2))`.
+///
+/// Logs go to the browser console; logging is off outside such calls.
+#[wasm_bindgen(js_name=with_logging)]
+pub fn with_logging(filter: &str, f: &js_sys::Function) -> Result<JsValue, JsValue> {
+    let filter: log::LogFilter = filter.parse().map_err(|e: String| JsValue::from_str(&e))?;
+    log::with_filter(filter, || f.call0(&JsValue::NULL))
+}
+
+#[wasm_bindgen(js_name=explain)]
+pub fn explain(input: String, year: Option<i32>, day: i32, part: i32) -> Result<String, String> {
+    aoc_solver::explain(&input, year_or_default(year), day, part)
+}
+
+/// `[{ year, day, title, puzzle_url, source_path, parts, explain_parts, extras }]`
+/// for every implemented day.
+#[wasm_bindgen(js_name=puzzles)]
+pub fn puzzles() -> JsValue {
+    let to_array = |parts: &[i32]| -> JsValue {
+        parts
+            .iter()
+            .map(|p| JsValue::from(*p))
+            .collect::<js_sys::Array>()
+            .into()
+    };
+    aoc_solver::puzzles()
+        .into_iter()
+        .map(|info| {
+            let object = js_sys::Object::new();
+            let extras: js_sys::Array = info.extras.iter().map(|e| JsValue::from_str(e)).collect();
+            for (key, value) in [
+                ("year", info.year.into()),
+                ("day", info.day.into()),
+                ("title", info.title.into()),
+                ("puzzle_url", info.puzzle_url.into()),
+                ("source_path", info.source_path.into()),
+                ("parts", to_array(&info.parts)),
+                ("explain_parts", to_array(&info.explain_parts)),
+                ("extras", extras.into()),
+            ] {
+                js_sys::Reflect::set(&object, &key.into(), &value).unwrap();
+            }
+            JsValue::from(object)
+        })
+        .collect::<js_sys::Array>()
+        .into()
+}
+
+/// `[{ year, day, title }]` of the days whose input format `input` matches.
+#[wasm_bindgen(js_name=identify)]
+pub fn identify(input: &str) -> JsValue {
+    aoc_solver::identify(input)
+        .into_iter()
+        .map(|(year, day)| {
+            let title = aoc_solver::solver(year, day).map_or("", |s| s.title());
+            let object = js_sys::Object::new();
+            for (key, value) in [
+                ("year", year.into()),
+                ("day", day.into()),
+                ("title", title.into()),
+            ] {
+                js_sys::Reflect::set(&object, &key.into(), &value).unwrap();
+            }
+            JsValue::from(object)
+        })
+        .collect::<js_sys::Array>()
+        .into()
+}
+
+/// `[{ name, input, answers: [{ part, answer }] }]` with the registered
+/// examples of a day, so the page can load them as input.
+#[wasm_bindgen(js_name=examples)]
+pub fn examples(year: Option<i32>, day: i32) -> JsValue {
+    aoc_solver::examples(year_or_default(year), day)
+        .iter()
+        .map(|example| {
+            let answers: js_sys::Array = example
+                .answers
+                .iter()
+                .map(|(part, answer)| {
+                    let object = js_sys::Object::new();
+                    for (key, value) in [("part", (*part).into()), ("answer", (*answer).into())] {
+                        js_sys::Reflect::set(&object, &key.into(), &value).unwrap();
+                    }
+                    JsValue::from(object)
+                })
+                .collect();
+            let object = js_sys::Object::new();
+            for (key, value) in [
+                ("name", example.name.into()),
+                ("input", example.input.into()),
+                ("answers", answers.into()),
+            ] {
+                js_sys::Reflect::set(&object, &key.into(), &value).unwrap();
+            }
+            JsValue::from(object)
+        })
+        .collect::<js_sys::Array>()
+        .into()
+}
+
+#[wasm_bindgen(js_name=available_years)]
+pub fn available_years() -> Vec<i32> {
+    aoc_solver::years()
+}
+
+#[wasm_bindgen(js_name=available_days)]
+pub fn available_days(year: Option<i32>) -> Vec<i32> {
+    aoc_solver::available(year_or_default(year))
+        .into_iter()
+        .map(|(day, _)| day)
+        .collect()
+}
+
+#[wasm_bindgen(js_name=available_parts)]
+pub fn available_parts(year: Option<i32>, day: i32) -> Vec<i32> {
+    aoc_solver::solver(year_or_default(year), day)
+        .map(|s| s.parts())
+        .unwrap_or_default()
+}
+
+#[wasm_bindgen(js_name=explain_parts)]
+pub fn explain_parts(year: Option<i32>, day: i32) -> Vec<i32> {
+    aoc_solver::solver(year_or_default(year), day)
+        .map(|s| s.explain_parts())
+        .unwrap_or_default()
 }
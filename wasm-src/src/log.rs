@@ -0,0 +1,222 @@
+//! Logging for solvers.
+//!
+//! Logging is off unless a call is wrapped in [`with_filter`], which turns
+//! it on for that call only. Messages go to `console.log` in wasm and to
+//! stderr in native builds. Solvers log with the crate's macros, which use
+//! the module path, e.g. `wasm_src::days::y2024::day_03`, as target:
+//!
+//! ```ignore
+//! crate::warn!("skipping malformed line {}", line);
+//! crate::debug!("enabled at {}", offset);
+//! crate::trace!("window {:?}", window);
+//! ```
+
+use derive_more::derive::Display;
+use std::cell::RefCell;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    #[display("ERROR")]
+    Error,
+    #[display("WARN")]
+    Warn,
+    #[display("INFO")]
+    Info,
+    #[display("DEBUG")]
+    Debug,
+    #[display("TRACE")]
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level '{s}'")),
+        }
+    }
+}
+
+/// Which messages to show.
+///
+/// Parsed from a comma separated list of `target=level` and `level`
+/// entries, e.g. `"day_03=trace,info"`. A target matches a module whose
+/// path is the target or ends in `::` and the target, so `day_1` matches
+/// `wasm_src::days::y2024::day_1` but not `day_10`, and `y2023::day_01`
+/// picks one event's day. The first matching entry decides; a bare level
+/// applies to everything else.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    targets: Vec<(String, Level)>,
+    default: Option<Level>,
+}
+
+impl LogFilter {
+    pub fn level(&self, target: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .find(|(t, _)| matches(t, target))
+            .map(|(_, level)| *level)
+            .or(self.default)
+    }
+}
+
+/// Whether `target` is the module `path` or a module ending in it.
+fn matches(target: &str, path: &str) -> bool {
+    match path.strip_suffix(target) {
+        Some(rest) => rest.is_empty() || rest.ends_with("::"),
+        None => false,
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<LogFilter, String> {
+        let mut filter = LogFilter::default();
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.trim().to_string(), level.trim().parse()?)),
+                None => filter.default = Some(entry.parse()?),
+            }
+        }
+        Ok(filter)
+    }
+}
+
+thread_local! {
+    static FILTER: RefCell<Option<LogFilter>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous filter, also when the wrapped call panics.
+struct Restore(Option<LogFilter>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        FILTER.with(|f| *f.borrow_mut() = previous);
+    }
+}
+
+/// Runs `f` with logging enabled as `filter` says.
+pub fn with_filter<R>(filter: LogFilter, f: impl FnOnce() -> R) -> R {
+    let _restore = Restore(FILTER.with(|current| current.borrow_mut().replace(filter)));
+    f()
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    FILTER.with(|f| {
+        f.borrow()
+            .as_ref()
+            .and_then(|filter| filter.level(target))
+            .is_some_and(|max| level <= max)
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn console_log(s: &str);
+}
+
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    let line = format!("[{level} {target}] {args}");
+    #[cfg(target_arch = "wasm32")]
+    console_log(&line);
+    #[cfg(not(target_arch = "wasm32"))]
+    eprintln!("{line}");
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{enabled, with_filter, Level, LogFilter};
+
+    #[test]
+    fn test_filter() {
+        let filter: LogFilter = "day_03=trace, info".parse().unwrap();
+        assert_eq!(
+            filter.level("wasm_src::days::y2024::day_03"),
+            Some(Level::Trace)
+        );
+        assert_eq!(
+            filter.level("wasm_src::days::y2024::day_04"),
+            Some(Level::Info)
+        );
+        assert!("day_03=loud".parse::<LogFilter>().is_err());
+    }
+
+    #[test]
+    fn test_filter_whole_segments() {
+        let filter: LogFilter = "day_1=debug, y2023::day_01=trace".parse().unwrap();
+        assert_eq!(
+            filter.level("wasm_src::days::y2024::day_1"),
+            Some(Level::Debug)
+        );
+        assert_eq!(filter.level("wasm_src::days::y2024::day_10"), None);
+        assert_eq!(filter.level("wasm_src::days::y2024::xday_1"), None);
+        assert_eq!(filter.level("day_1"), Some(Level::Debug));
+        assert_eq!(
+            filter.level("wasm_src::days::y2023::day_01"),
+            Some(Level::Trace)
+        );
+        assert_eq!(filter.level("wasm_src::days::y2024::day_01"), None);
+    }
+
+    #[test]
+    fn test_with_filter() {
+        let target = "wasm_src::days::y2024::day_06";
+        assert!(!enabled(Level::Error, target));
+        let filter = "day_06=debug".parse().unwrap();
+        with_filter(filter, || {
+            assert!(enabled(Level::Debug, target));
+            assert!(!enabled(Level::Trace, target));
+            assert!(!enabled(Level::Error, "wasm_src::days::y2024::day_07"));
+        });
+        assert!(!enabled(Level::Error, target));
+    }
+}
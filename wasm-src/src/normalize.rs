@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+
+/// What to do with whitespace at the end of the input and of its lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trailing {
+    Keep,
+    /// Remove whitespace, newlines included, at the end of the input.
+    TrimEnd,
+    /// Like `TrimEnd`, and also remove spaces and tabs ending any line.
+    TrimLines,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tabs {
+    Keep,
+    /// Replace every tab with a single space.
+    ToSpace,
+}
+
+/// How raw input is cleaned up before a day parses it, so that input pasted
+/// or uploaded from any OS parses the same way.
+///
+/// Days pick theirs with [`Solver::NORMALIZE`].
+///
+/// [`Solver::NORMALIZE`]: crate::aoc_solver::Solver::NORMALIZE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    /// Remove a leading byte order mark.
+    pub strip_bom: bool,
+    /// Turn `\r\n` and lone `\r` into `\n`.
+    pub unify_newlines: bool,
+    pub trailing: Trailing,
+    pub tabs: Tabs,
+}
+
+impl Normalize {
+    pub const DEFAULT: Normalize = Normalize {
+        strip_bom: true,
+        unify_newlines: true,
+        trailing: Trailing::TrimLines,
+        tabs: Tabs::Keep,
+    };
+
+    /// Leaves the input untouched.
+    pub const RAW: Normalize = Normalize {
+        strip_bom: false,
+        unify_newlines: false,
+        trailing: Trailing::Keep,
+        tabs: Tabs::Keep,
+    };
+
+    /// Applies the policy, borrowing the input when nothing changes.
+    pub fn apply<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let input = match self.strip_bom {
+            true => input.strip_prefix('\u{feff}').unwrap_or(input),
+            false => input,
+        };
+        let mut text = Cow::Borrowed(input);
+        if self.unify_newlines && text.contains('\r') {
+            text = Cow::Owned(text.replace("\r\n", "\n").replace('\r', "\n"));
+        }
+        if self.tabs == Tabs::ToSpace && text.contains('\t') {
+            text = Cow::Owned(text.replace('\t', " "));
+        }
+        if self.trailing == Trailing::TrimLines
+            && text.split('\n').any(|line| line.ends_with([' ', '\t']))
+        {
+            let lines: Vec<&str> = text
+                .split('\n')
+                .map(|line| line.trim_end_matches([' ', '\t']))
+                .collect();
+            text = Cow::Owned(lines.join("\n"));
+        }
+        if self.trailing != Trailing::Keep {
+            text = match text {
+                Cow::Borrowed(s) => Cow::Borrowed(s.trim_end()),
+                Cow::Owned(s) => Cow::Owned(s.trim_end().to_string()),
+            };
+        }
+        text
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Normalize {
+        Normalize::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Normalize, Tabs, Trailing};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_default() {
+        let normalized = Normalize::DEFAULT.apply("\u{feff}1 2  \r\n3\t4\r\r\n\n");
+        assert_eq!(normalized, "1 2\n3\t4");
+        assert!(matches!(
+            Normalize::DEFAULT.apply("1 2\n3 4\n"),
+            Cow::Borrowed("1 2\n3 4")
+        ));
+    }
+
+    #[test]
+    fn test_options() {
+        let input = "a\tb \r\nc \n";
+        assert_eq!(Normalize::RAW.apply(input), input);
+        let tabs = Normalize {
+            tabs: Tabs::ToSpace,
+            trailing: Trailing::TrimEnd,
+            ..Normalize::DEFAULT
+        };
+        assert_eq!(tabs.apply(input), "a b \nc");
+    }
+}
@@ -0,0 +1,16 @@
+/// Receives how far a solve has come.
+///
+/// `fraction` goes from 0.0 to 1.0 and `message` optionally says what the
+/// solver is working on. Solvers report through [`Budget::progress`] at a
+/// coarse granularity, e.g. once per row or per equation.
+///
+/// [`Budget::progress`]: crate::budget::Budget::progress
+pub trait Progress {
+    fn report(&self, fraction: f64, message: Option<&str>);
+}
+
+impl<F: Fn(f64, Option<&str>)> Progress for F {
+    fn report(&self, fraction: f64, message: Option<&str>) {
+        self(fraction, message)
+    }
+}
@@ -0,0 +1,241 @@
+//! Shrinking of failing inputs to a small regression test.
+//!
+//! A generated input that makes a part panic or disagree with its reference
+//! is usually far bigger than the failure needs. [`shrink`] removes pieces of
+//! it with delta debugging, following the day's [`Structure`], for as long as
+//! the input keeps failing, and [`regression_test`] writes what is left as a
+//! test to paste into the day's `tests` module.
+
+use crate::aoc_solver::DynSolver;
+use crate::budget::Budget;
+use crate::differential;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The pieces a day's input can lose and still be in the puzzle's format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Structure {
+    /// Whole lines, then the items of each line, each ending at one of the
+    /// separators.
+    Lines(&'static [char]),
+    /// Rows and columns of a grid.
+    Grid,
+    /// Single characters.
+    Chars,
+}
+
+/// A failure that a shrunk input has to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// Solving the part panics.
+    Panic(i32),
+    /// A part disagrees with its reference, see [`differential::compare`].
+    Disagreement,
+}
+
+impl Failure {
+    /// Whether `solver` fails this way on `input`, with `max_steps` to
+    /// spend on each part.
+    pub fn occurs(&self, solver: &dyn DynSolver, input: &str, max_steps: u64) -> bool {
+        match *self {
+            Failure::Panic(part) => {
+                let budget = Budget::max_steps(max_steps);
+                let solve = || solver.solve_with_budget(input, part, &budget);
+                panic::catch_unwind(AssertUnwindSafe(solve)).is_err()
+            }
+            Failure::Disagreement => differential::compare(solver, input, max_steps).is_some(),
+        }
+    }
+}
+
+/// Delta debugging: a subsequence of `items` that still `fails`, from which
+/// no single chunk the search tried can be removed.
+fn ddmin<T: Clone>(mut items: Vec<T>, fails: &mut dyn FnMut(&[T]) -> bool) -> Vec<T> {
+    if fails(&[]) {
+        return Vec::new();
+    }
+    let mut chunks = 2;
+    while items.len() >= 2 {
+        let size = items.len().div_ceil(chunks);
+        let starts: Vec<usize> = (0..items.len()).step_by(size).collect();
+        let subset = starts.iter().find_map(|&start| {
+            let subset = &items[start..(start + size).min(items.len())];
+            fails(subset).then(|| subset.to_vec())
+        });
+        if let Some(subset) = subset {
+            items = subset;
+            chunks = 2;
+            continue;
+        }
+        let complement = starts.iter().find_map(|&start| {
+            let mut complement = items[..start].to_vec();
+            complement.extend_from_slice(&items[(start + size).min(items.len())..]);
+            fails(&complement).then_some(complement)
+        });
+        if let Some(complement) = complement {
+            items = complement;
+            chunks = (chunks - 1).max(2);
+            continue;
+        }
+        if chunks >= items.len() {
+            break;
+        }
+        chunks = (chunks * 2).min(items.len());
+    }
+    items
+}
+
+fn shrink_lines(input: &str, separators: &[char], fails: &mut dyn FnMut(&str) -> bool) -> String {
+    let lines: Vec<String> = input.split_inclusive('\n').map(String::from).collect();
+    let mut lines = ddmin(lines, &mut |lines| fails(&lines.concat()));
+    for i in 0..lines.len() {
+        let newline = if lines[i].ends_with('\n') { "\n" } else { "" };
+        let text = lines[i].trim_end_matches('\n').to_string();
+        let items: Vec<&str> = text.split_inclusive(|c| separators.contains(&c)).collect();
+        let items = ddmin(items, &mut |items| {
+            let mut candidate = lines.clone();
+            candidate[i] = items.concat() + newline;
+            fails(&candidate.concat())
+        });
+        lines[i] = items.concat() + newline;
+    }
+    lines.concat()
+}
+
+/// Rows are the lines, columns the characters at the same index in each.
+fn shrink_grid(input: &str, fails: &mut dyn FnMut(&str) -> bool) -> String {
+    let render = |rows: &[Vec<char>]| {
+        let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+        rows.join("\n")
+    };
+    let rows: Vec<Vec<char>> = input.split('\n').map(|row| row.chars().collect()).collect();
+    let rows = ddmin(rows, &mut |rows| fails(&render(rows)));
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let keep_columns = |columns: &[usize]| -> Vec<Vec<char>> {
+        rows.iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .filter_map(|&c| row.get(c).copied())
+                    .collect()
+            })
+            .collect()
+    };
+    let columns = ddmin((0..width).collect(), &mut |columns| {
+        fails(&render(&keep_columns(columns)))
+    });
+    render(&keep_columns(&columns))
+}
+
+fn shrink_chars(input: &str, fails: &mut dyn FnMut(&str) -> bool) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let chars = ddmin(chars, &mut |chars| fails(&chars.iter().collect::<String>()));
+    chars.into_iter().collect()
+}
+
+/// The smallest input found that still `fails`, or `input` itself if it
+/// does not fail.
+///
+/// Pieces of the input are removed as `structure` says, over and over until
+/// no piece can be.
+pub fn shrink(input: &str, structure: Structure, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut input = input.to_string();
+    if !fails(&input) {
+        return input;
+    }
+    loop {
+        let shrunk = match structure {
+            Structure::Lines(separators) => shrink_lines(&input, separators, &mut fails),
+            Structure::Grid => shrink_grid(&input, &mut fails),
+            Structure::Chars => shrink_chars(&input, &mut fails),
+        };
+        if shrunk == input {
+            return input;
+        }
+        input = shrunk;
+    }
+}
+
+/// A test for the `tests` module of a day, which fails for as long as the
+/// day fails this way on `input`.
+pub fn regression_test(
+    year: i32,
+    day: i32,
+    failure: Failure,
+    input: &str,
+    max_steps: u64,
+) -> String {
+    let (name, check) = match failure {
+        Failure::Panic(part) => (
+            format!("test_shrunk_part_{part}"),
+            format!(
+                "    let budget = crate::budget::Budget::max_steps({max_steps});\n    \
+                 let _ = solver.solve_with_budget(input, {part}, &budget);\n"
+            ),
+        ),
+        Failure::Disagreement => (
+            "test_shrunk_reference".to_string(),
+            format!(
+                "    let disagreement = crate::differential::compare(solver, input, {max_steps});\n    \
+                 assert_eq!(disagreement, None);\n"
+            ),
+        ),
+    };
+    format!(
+        "#[test]\nfn {name}() {{\n    \
+         let input = {input:?};\n    \
+         let solver = crate::aoc_solver::solver({year}, {day}).unwrap();\n\
+         {check}}}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{regression_test, shrink, Failure, Structure};
+
+    #[test]
+    fn test_shrink_lines() {
+        let input = "1 2 3\n4 5 6\n7 8 9\n";
+        let fails = |input: &str| input.contains('5') && input.contains("8 9");
+        assert_eq!(shrink(input, Structure::Lines(&[' ']), fails), "5 \n8 9\n");
+        assert_eq!(
+            shrink(input, Structure::Lines(&[]), fails),
+            "4 5 6\n7 8 9\n"
+        );
+        assert_eq!(shrink(input, Structure::Lines(&[]), |_| false), input);
+    }
+
+    #[test]
+    fn test_shrink_grid() {
+        let input = "....\n.#..\n..^.\n....";
+        // An obstacle up and to the left of the guard.
+        let fails = |input: &str| {
+            let find = |c| {
+                let (row, line) = input.lines().enumerate().find(|(_, l)| l.contains(c))?;
+                Some((row, line.find(c)?))
+            };
+            matches!((find('#'), find('^')), (Some(a), Some(b)) if a.0 < b.0 && a.1 < b.1)
+        };
+        assert_eq!(shrink(input, Structure::Grid, fails), "#.\n.^");
+    }
+
+    #[test]
+    fn test_shrink_chars() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)";
+        let fails = |input: &str| input.matches("mul").count() >= 2;
+        assert_eq!(shrink(input, Structure::Chars, fails), "mulmul");
+    }
+
+    #[test]
+    fn test_regression_test() {
+        assert_eq!(
+            regression_test(2024, 9, Failure::Panic(2), "1\n2", 100),
+            "#[test]\n\
+             fn test_shrunk_part_2() {\n    \
+             let input = \"1\\n2\";\n    \
+             let solver = crate::aoc_solver::solver(2024, 9).unwrap();\n    \
+             let budget = crate::budget::Budget::max_steps(100);\n    \
+             let _ = solver.solve_with_budget(input, 2, &budget);\n\
+             }\n"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Verification of answers against keyed hashes.
+//!
+//! Answers to real inputs are personal, and small enough to guess, so the
+//! repository only keeps an HMAC-SHA256 of each one, recorded per user, day
+//! and part in an answer store. The HMAC is keyed by a secret [`Key`] that
+//! each user keeps outside the repository, so the committed hashes cannot
+//! be checked against guesses by anyone else. A store is a text file with
+//! one entry per line:
+//!
+//! ```text
+//! # year day part user hmac-sha256
+//! 2024 9 1 alice 9a3f…07
+//! ```
+
+use crate::answer::Answer;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// A user's secret for hashing answers, kept as hex in a file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key(Vec<u8>);
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+impl Key {
+    /// A fresh key of 256 bits from the OS random number generator.
+    pub fn generate() -> io::Result<Key> {
+        let mut bytes = vec![0; 32];
+        getrandom::getrandom(&mut bytes)?;
+        Ok(Key(bytes))
+    }
+
+    /// Reads the key at `path`, `None` if there is none.
+    pub fn load(path: &Path) -> io::Result<Option<Key>> {
+        match fs::read_to_string(path) {
+            Ok(text) => match from_hex(text.trim()).filter(|bytes| !bytes.is_empty()) {
+                Some(bytes) => Ok(Some(Key(bytes))),
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: expected a key in hex", path.display()),
+                )),
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads the key at `path`, generating and saving one if there is none.
+    pub fn load_or_create(path: &Path) -> io::Result<Key> {
+        if let Some(key) = Key::load(path)? {
+            return Ok(key);
+        }
+        let key = Key::generate()?;
+        fs::write(path, to_hex(&key.0) + "\n")?;
+        Ok(key)
+    }
+
+    fn hash(&self, year: i32, day: i32, part: i32, answer: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.0).expect("HMAC takes keys of any size");
+        mac.update(format!("{year} {day} {part}:{answer}").as_bytes());
+        to_hex(&mac.finalize().into_bytes())
+    }
+}
+
+/// The recorded hash of one user's answer to one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    pub user: String,
+    hash: String,
+}
+
+impl Entry {
+    /// Hashes `answer` with the user's `key`.
+    pub fn new(year: i32, day: i32, part: i32, user: &str, key: &Key, answer: &Answer) -> Entry {
+        Entry {
+            year,
+            day,
+            part,
+            user: user.to_string(),
+            hash: key.hash(year, day, part, &answer.to_string()),
+        }
+    }
+
+    /// Whether `answer`, as displayed, is the recorded one, given the key it
+    /// was recorded with.
+    pub fn matches(&self, key: &Key, answer: &str) -> bool {
+        key.hash(self.year, self.day, self.part, answer) == self.hash
+    }
+
+    fn key(&self) -> (i32, i32, i32, &str) {
+        (self.year, self.day, self.part, &self.user)
+    }
+}
+
+/// Recorded answer hashes, ordered by year, day, part and user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    entries: Vec<Entry>,
+}
+
+impl AnswerStore {
+    /// Parses a store, skipping blank lines and `#` comments.
+    pub fn parse(text: &str) -> Result<AnswerStore, String> {
+        let mut store = AnswerStore::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || format!("line {}: expected `year day part user hash`", i + 1);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (year, day, part, user, hash) = match fields[..] {
+                [year, day, part, user, hash] => (year, day, part, user, hash),
+                _ => return Err(invalid()),
+            };
+            let number = |field: &str| field.parse::<i32>().map_err(|_| invalid());
+            store.record(Entry {
+                year: number(year)?,
+                day: number(day)?,
+                part: number(part)?,
+                user: user.to_string(),
+                hash: hash.to_string(),
+            });
+        }
+        Ok(store)
+    }
+
+    /// Reads the store at `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> io::Result<AnswerStore> {
+        match fs::read_to_string(path) {
+            Ok(text) => AnswerStore::parse(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, year: i32, day: i32, part: i32, user: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|e| e.key() == (year, day, part, user))
+    }
+
+    /// Whether any user has an entry for the part.
+    pub fn recorded(&self, year: i32, day: i32, part: i32) -> bool {
+        self.entries
+            .iter()
+            .any(|e| (e.year, e.day, e.part) == (year, day, part))
+    }
+
+    /// Adds an entry, replacing any earlier one for the same user and part.
+    pub fn record(&mut self, entry: Entry) {
+        match self.entries.binary_search_by(|e| e.key().cmp(&entry.key())) {
+            Ok(i) => self.entries[i] = entry,
+            Err(i) => self.entries.insert(i, entry),
+        }
+    }
+
+    /// Whether `answer` is the recorded one, `None` if nothing is recorded.
+    pub fn verify(
+        &self,
+        year: i32,
+        day: i32,
+        part: i32,
+        user: &str,
+        key: &Key,
+        answer: &Answer,
+    ) -> Option<bool> {
+        self.get(year, day, part, user)
+            .map(|entry| entry.matches(key, &answer.to_string()))
+    }
+}
+
+impl fmt::Display for AnswerStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# year day part user hmac-sha256")?;
+        for e in &self.entries {
+            writeln!(f, "{} {} {} {} {}", e.year, e.day, e.part, e.user, e.hash)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnswerStore, Entry, Key};
+    use crate::answer::Answer;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_verify() {
+        let key = Key::generate().unwrap();
+        let mut store = AnswerStore::default();
+        store.record(Entry::new(2024, 9, 1, "main", &key, &Answer::Integer(1928)));
+        store.record(Entry::new(2024, 1, 2, "main", &key, &Answer::Integer(31)));
+        let answer = Answer::BigInteger(1928);
+        assert_eq!(store.verify(2024, 9, 1, "main", &key, &answer), Some(true));
+        assert_eq!(
+            store.verify(2024, 9, 1, "main", &key, &Answer::Integer(7)),
+            Some(false)
+        );
+        assert_eq!(store.verify(2024, 9, 1, "other", &key, &answer), None);
+
+        let text = store.to_string();
+        assert!(text.split_whitespace().all(|field| field != "1928"));
+        assert!(text.lines().nth(1).unwrap().starts_with("2024 1 2 main "));
+        assert_eq!(AnswerStore::parse(&text), Ok(store));
+    }
+
+    #[test]
+    fn test_key() {
+        let answer = Answer::Integer(1928);
+        let (first, second) = (Key::generate().unwrap(), Key::generate().unwrap());
+        assert_ne!(first, second);
+        let entry = Entry::new(2024, 9, 1, "main", &first, &answer);
+        assert!(entry.matches(&first, "1928"));
+        assert!(!entry.matches(&second, "1928"));
+        // The hash also covers the day and part.
+        assert_ne!(
+            entry.hash,
+            Entry::new(2024, 9, 2, "main", &first, &answer).hash
+        );
+
+        let path = env::temp_dir().join(format!("aoc-key-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(Key::load(&path).unwrap(), None);
+        let key = Key::load_or_create(&path).unwrap();
+        assert_eq!(Key::load(&path).unwrap(), Some(key.clone()));
+        assert_eq!(Key::load_or_create(&path).unwrap(), key);
+        fs::write(&path, "not hex\n").unwrap();
+        assert!(Key::load(&path).is_err());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            AnswerStore::parse("# comment\n2024 9 1 main\n"),
+            Err("line 2: expected `year day part user hash`".to_string())
+        );
+        assert!(AnswerStore::parse("2024 nine 1 main hash\n").is_err());
+    }
+}
@@ -0,0 +1,40 @@
+//! Compares every day that has reference parts against them on generated
+//! inputs, see [`wasm_src::differential`]. The first input a day disagrees
+//! on is shrunk and reported as a regression test.
+
+use wasm_src::aoc_solver;
+use wasm_src::differential;
+use wasm_src::rng::Rng;
+use wasm_src::shrink::{self, Failure};
+
+const MAX_STEPS: u64 = 1_000_000;
+const INPUTS_PER_DAY: usize = 300;
+/// Sizes of the generated inputs, cycled through; small inputs keep the
+/// reference parts fast and a disagreement easy to read.
+const MAX_SIZE: usize = 8;
+
+#[test]
+fn test_differential() {
+    let mut rng = Rng::new(0x2024_1225);
+    for info in aoc_solver::puzzles() {
+        let solver = aoc_solver::solver(info.year, info.day).unwrap();
+        if solver.reference_parts().is_empty() {
+            continue;
+        }
+        let inputs: Vec<String> = (0..INPUTS_PER_DAY)
+            .map(|i| solver.generate(&mut rng, i % MAX_SIZE + 1))
+            .collect();
+        if let Some(disagreement) = differential::first_disagreement(solver, inputs, MAX_STEPS) {
+            let fails = |input: &str| Failure::Disagreement.occurs(solver, input, MAX_STEPS);
+            let input = shrink::shrink(&disagreement.input, solver.structure(), fails);
+            let test = shrink::regression_test(
+                info.year,
+                info.day,
+                Failure::Disagreement,
+                &input,
+                MAX_STEPS,
+            );
+            panic!("day {}: {}\n\nshrunk:\n{}", info.day, disagreement, test);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Checks real inputs against their known answers, see [`wasm_src::golden`].
+//!
+//! Inputs are read from the directory named by `AOC_DATA`, or the
+//! repository's `data` directory when it is unset, and answers also from
+//! the hashes `AOC_USER` recorded in `answers.txt`. Days without inputs are
+//! skipped; run with `--nocapture` to see the table.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use wasm_src::golden;
+use wasm_src::verify::AnswerStore;
+
+#[test]
+fn test_golden() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = env::var_os(golden::DATA_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir.join("../data"));
+    let store = AnswerStore::load(&manifest_dir.join("answers.txt")).unwrap();
+    let user = env::var(golden::USER_VAR).ok();
+    let checks = golden::run(&dir, &store, user.as_deref()).unwrap();
+    let table = golden::table(&checks);
+    println!("{table}");
+    assert!(golden::passed(&checks), "{}", table);
+}
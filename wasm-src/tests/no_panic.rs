@@ -0,0 +1,141 @@
+//! Feeds random, generated and adversarial inputs to every registered day
+//! and checks
+//! that solving and explaining return, with an answer or an error, instead
+//! of panicking. Inputs that make a part panic are shrunk and reported as
+//! regression tests.
+
+use std::panic::{self, AssertUnwindSafe};
+use wasm_src::aoc_solver;
+use wasm_src::rng::Rng;
+use wasm_src::shrink::{self, Failure};
+
+/// Steps each solve may take, so inputs that make a solver run for long
+/// fail fast with a budget error.
+const MAX_STEPS: u64 = 100_000;
+const MAX_EXPLAIN_INPUT: usize = 1024;
+const RANDOM_INPUTS_PER_DAY: usize = 300;
+const GENERATED_INPUTS_PER_DAY: usize = 50;
+
+/// Characters the puzzle inputs are made of, plus some they are not.
+const ALPHABETS: &[&str] = &[
+    "0123456789 \n",
+    "0123456789|,\n",
+    "0123456789: \n",
+    "XMAS\n",
+    ".#^\n",
+    ".aA0\n",
+    "mul(),don't0123456789",
+    "-+é\r\t\0x ",
+];
+
+/// Snippets that push parsers and solvers towards their edge cases.
+const TOKENS: &[&str] = &[
+    "\n\n",
+    "\r\n",
+    "^",
+    "0",
+    "-2147483648",
+    "2147483647",
+    "4294967295",
+    "18446744073709551615",
+    "99999999999999999999999",
+    "mul(999,999)",
+    "do()",
+    "don't()",
+    "1|2",
+    ": ",
+    "é",
+];
+
+fn random_input(rng: &mut Rng) -> String {
+    let alphabet: Vec<char> = rng.pick(ALPHABETS).chars().collect();
+    let len = rng.below(80);
+    let mut input = String::new();
+    for _ in 0..len {
+        if rng.below(8) == 0 {
+            input.push_str(rng.pick::<&str>(TOKENS));
+        } else {
+            input.push(alphabet[rng.below(alphabet.len())]);
+        }
+    }
+    input
+}
+
+fn adversarial_inputs() -> Vec<String> {
+    let mut inputs: Vec<String> = [
+        "",
+        "\n",
+        " ",
+        "0",
+        "00",
+        "^",
+        "^^",
+        "#",
+        "#\n^#\n",
+        ".#.\n#^#\n.#.",
+        "X",
+        "XMAS\nXM",
+        "1|2\n\n",
+        "1|2\n\n\n",
+        "1|2\n\n1,,2",
+        "1: ",
+        "1:",
+        "0: 0",
+        "18446744073709551615: 18446744073709551615 18446744073709551615 9",
+        "1: 1000000000 1000000000 1000000000",
+        "-2147483648   2147483647\n2147483647   -2147483648",
+        "2147483647 -2147483648 2147483647",
+        "mul(999,999)",
+        "a",
+        "aa",
+        "a\n\n\n\na",
+        "é",
+        "\r\n",
+        "0000",
+        "1010101",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    inputs.push(format!("1: {}", vec!["1"; 70].join(" ")));
+    inputs.push(format!("1: {}", vec!["2"; 30].join(" ")));
+    inputs.push("9".repeat(20_001));
+    inputs.push("2147483647   2147483647\n".repeat(2000));
+    inputs.push(format!("{}\n", "mul(999,999)".repeat(3000)));
+    inputs
+}
+
+#[test]
+fn test_no_panic() {
+    let mut rng = Rng::new(0x2024_1201);
+    let mut failures = Vec::new();
+    for info in aoc_solver::puzzles() {
+        let solver = aoc_solver::solver(info.year, info.day).unwrap();
+        let mut inputs = adversarial_inputs();
+        inputs.extend((0..RANDOM_INPUTS_PER_DAY).map(|_| random_input(&mut rng)));
+        inputs.extend((0..GENERATED_INPUTS_PER_DAY).map(|i| solver.generate(&mut rng, i)));
+        for input in inputs {
+            for part in info.parts.iter().copied() {
+                let failure = Failure::Panic(part);
+                if failure.occurs(solver, &input, MAX_STEPS) {
+                    let fails = |input: &str| failure.occurs(solver, input, MAX_STEPS);
+                    let input = shrink::shrink(&input, solver.structure(), fails);
+                    failures.push(shrink::regression_test(
+                        info.year, info.day, failure, &input, MAX_STEPS,
+                    ));
+                }
+            }
+            // Explanations have no step budget, so only small inputs.
+            if input.len() > MAX_EXPLAIN_INPUT {
+                continue;
+            }
+            for part in info.explain_parts.iter().copied() {
+                let explain = || solver.explain(&input, part).map(|_| ());
+                if panic::catch_unwind(AssertUnwindSafe(explain)).is_err() {
+                    failures.push(format!("day {} explain {part} on {input:?}", info.day));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "panicked:\n{}", failures.join("\n"));
+}